@@ -3,9 +3,47 @@ pub const STABLECOIN_SEED: &[u8] = b"stablecoin";
 pub const ROLE_SEED: &[u8] = b"role";
 pub const MINTER_SEED: &[u8] = b"minter";
 pub const BLACKLIST_SEED: &[u8] = b"blacklist";
+pub const FREEZE_AUTHORITY_SEED: &[u8] = b"freeze_auth";
+pub const ISSUER_REGISTRY_SEED: &[u8] = b"issuer_registry";
+pub const MINT_LOCK_SEED: &[u8] = b"mint_lock";
+pub const MINTER_STATS_SEED: &[u8] = b"minter_stats";
 
 /// Validation limits
 pub const MAX_NAME_LEN: usize = 32;
 pub const MAX_SYMBOL_LEN: usize = 10;
 pub const MAX_URI_LEN: usize = 200;
+/// Hard cap for the per-stablecoin `max_uri_len` override chosen at init
+pub const MAX_URI_LEN_CAP: usize = 512;
 pub const MAX_REASON_LEN: usize = 100;
+/// Per-operation batch limits, sized to fit a transaction and its CU budget.
+/// Each blacklist item costs 64 bytes (address + PDA key) and a PDA creation.
+pub const MAX_BATCH_BLACKLIST: usize = 10;
+/// Each minter item costs 64 bytes (minter + PDA key) and possibly a PDA creation.
+pub const MAX_BATCH_MINTER: usize = 10;
+/// Each role item costs 32 bytes (role PDA key) and no PDA creation.
+pub const MAX_BATCH_ROLES: usize = 20;
+pub const MAX_REGISTRY_SYMBOLS: usize = 32;
+pub const MAX_FREEZE_PROTECTED: usize = 8;
+pub const MAX_REDEMPTION_ACCOUNTS: usize = 8;
+pub const MAX_DENIED_INTEGRATIONS: usize = 8;
+
+/// Basis-point math
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Highest share of a seizure that can be withheld as an enforcement fee (10%)
+pub const MAX_SEIZE_FEE_BPS: u16 = 1_000;
+/// Shortest allowed minter quota epoch; anything smaller effectively disables the quota
+pub const MIN_EPOCH_DURATION: i64 = 60;
+
+/// Mint authorities reported by `check_authorities` when they no longer point
+/// at the PDA this program signs with.
+pub const AUTHORITY_MINT: u8 = 1 << 0;
+pub const AUTHORITY_FREEZE: u8 = 1 << 1;
+pub const AUTHORITY_CLOSE: u8 = 1 << 2;
+pub const AUTHORITY_PERMANENT_DELEGATE: u8 = 1 << 3;
+
+/// Event categories that can be suppressed via `StablecoinState::event_mask`.
+/// Compliance and administrative events are always emitted.
+pub const EVENT_SUPPLY: u8 = 1 << 0; // TokensMinted, TokensBurned
+pub const EVENT_MINTER: u8 = 1 << 1; // MinterQuotaExhausted
+pub const EVENT_FREEZE: u8 = 1 << 2; // AccountFrozen, AccountThawed
+pub const EVENT_MASK_ALL: u8 = EVENT_SUPPLY | EVENT_MINTER | EVENT_FREEZE;
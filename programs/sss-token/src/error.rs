@@ -29,7 +29,7 @@ pub enum StablecoinError {
     #[msg("Symbol too long (max 10 characters)")]
     SymbolTooLong,
 
-    #[msg("URI too long (max 200 characters)")]
+    #[msg("URI too long (max 200 characters unless max_uri_len is raised at init, up to 512)")]
     UriTooLong,
 
     #[msg("Reason too long (max 100 characters)")]
@@ -43,4 +43,154 @@ pub enum StablecoinError {
 
     #[msg("Invalid role configuration")]
     InvalidRoleConfig,
+
+    #[msg("Token account is not owned by the Token-2022 program")]
+    InvalidTokenAccount,
+
+    #[msg("Vault token account must be owned by the stablecoin PDA")]
+    InvalidVault,
+
+    #[msg("Mint account does not match the stablecoin's mint")]
+    MintMismatch,
+
+    #[msg("Introspection guard requires the destination token account and its blacklist PDA")]
+    IntrospectionAccountMissing,
+
+    #[msg("Freeze authority account does not match the mint's freeze authority")]
+    InvalidFreezeAuthority,
+
+    #[msg("Batch exceeds the maximum number of items")]
+    BatchTooLarge,
+
+    #[msg("Remaining accounts do not match the batch items")]
+    BatchAccountMismatch,
+
+    #[msg("Treasury creation requires the treasury ATA, associated token program and freeze authority")]
+    TreasuryAccountsMissing,
+
+    #[msg("Epoch duration must be 0 (disabled) or at least MIN_EPOCH_DURATION seconds")]
+    InvalidEpochDuration,
+
+    #[msg("Burn would exceed the total minted through this program")]
+    SupplyAccountingError,
+
+    #[msg("Event mask contains unknown event categories")]
+    InvalidEventMask,
+
+    #[msg("Recipient token account belongs to a different mint")]
+    RecipientMintMismatch,
+
+    #[msg("Account is not a role, minter or blacklist PDA of this stablecoin")]
+    NotRepairable,
+
+    #[msg("Amount exceeds the configured maximum for a single mint")]
+    MintAmountTooLarge,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Authority transfer cooldown has not elapsed")]
+    AuthorityTransferCooldown,
+
+    #[msg("Cooldown must not be negative")]
+    InvalidCooldown,
+
+    #[msg("Symbol was already used by this issuer")]
+    SymbolAlreadyUsed,
+
+    #[msg("Issuer registry has no room for more symbols")]
+    IssuerRegistryFull,
+
+    #[msg("Issuer registry account is missing or does not belong to the authority")]
+    IssuerRegistryMissing,
+
+    #[msg("Account is protected from freezing")]
+    FreezeProtected,
+
+    #[msg("Freeze-protected list is full")]
+    FreezeProtectedListFull,
+
+    #[msg("Fee exceeds the maximum basis points")]
+    InvalidFeeBps,
+
+    #[msg("Seizure fee requires the configured fee treasury as a remaining account")]
+    FeeTreasuryMissing,
+
+    #[msg("Holder would exceed the maximum number of roles")]
+    TooManyRoles,
+
+    #[msg("Burns are restricted to designated redemption accounts")]
+    RedemptionAccountNotAllowed,
+
+    #[msg("Redemption account list is full")]
+    RedemptionListFull,
+
+    #[msg("Mint lock duration must not be negative")]
+    InvalidMintLockDuration,
+
+    #[msg("Mint lock account missing or does not match the recipient")]
+    MintLockAccountMissing,
+
+    #[msg("Reserve oracle account missing or does not match the configured oracle")]
+    ReserveOracleMissing,
+
+    #[msg("Reserve oracle account data is too short")]
+    InvalidReserveOracle,
+
+    #[msg("Mint would push supply above the declared reserves")]
+    ReserveRatioBreached,
+
+    #[msg("Recovery inactivity period must be positive")]
+    InvalidRecoveryPeriod,
+
+    #[msg("Signer is not the recovery authority")]
+    NotRecoveryAuthority,
+
+    #[msg("Authority has been active within the recovery inactivity period")]
+    AuthorityStillActive,
+
+    #[msg("Blacklist entry belongs to another namespace")]
+    BlacklistNamespaceMismatch,
+
+    #[msg("Amount exceeds the configured maximum for a single seizure")]
+    SeizeAmountTooLarge,
+
+    #[msg("Seize amount exceeds the source account balance")]
+    SeizeAmountExceedsBalance,
+
+    #[msg("Display decimals must not exceed the mint's decimals")]
+    InvalidDisplayDecimals,
+
+    #[msg("Denied integration list is full")]
+    DeniedIntegrationListFull,
+
+    #[msg("Epoch alignment offset must not be negative")]
+    InvalidEpochAlignment,
+
+    #[msg("Stablecoin must be paused before it can be closed")]
+    NotPaused,
+
+    #[msg("Mint still has tokens in circulation")]
+    SupplyNotZero,
+
+    #[msg("Blacklist expiry must be in the future")]
+    InvalidBlacklistExpiry,
+
+    #[msg("Blacklist entry has expired")]
+    BlacklistExpired,
+
+    #[msg("Configuration is frozen while an authority transfer is pending")]
+    ConfigFrozenDuringTransfer,
+
+    #[msg("Blacklist entry is not the source owner's blacklist PDA")]
+    BlacklistEntryMismatch,
+
+    #[msg("minted_amount is the quota counter of a minter without epochs and cannot be reset")]
+    ResetWouldFreeQuota,
+
+    #[msg("A reserve oracle needs a nonzero minimum reserve ratio")]
+    InvalidReserveRatio,
+
+    #[msg("A nonzero seizure fee needs a fee treasury")]
+    FeeTreasuryNotSet,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{BlacklistReasonCode, HookMode};
+
 #[event]
 pub struct StablecoinInitialized {
     pub stablecoin: Pubkey,
@@ -8,6 +10,9 @@ pub struct StablecoinInitialized {
     pub name: String,
     pub symbol: String,
     pub is_sss2: bool,
+    pub decimals: u8,
+    /// Issuer's preferred display precision for amounts (<= decimals)
+    pub display_decimals: u8,
     pub timestamp: i64,
 }
 
@@ -19,6 +24,8 @@ pub struct TokensMinted {
     pub amount: u64,
     pub total_minted: u64,
     pub timestamp: i64,
+    /// Client reference (idempotency key or memo) for ledger reconciliation
+    pub reference: Option<[u8; 16]>,
 }
 
 #[event]
@@ -69,6 +76,7 @@ pub struct RolesUpdated {
     pub is_pauser: bool,
     pub is_blacklister: bool,
     pub is_seizer: bool,
+    pub is_metadata_updater: bool,
     pub updated_by: Pubkey,
     pub timestamp: i64,
 }
@@ -78,10 +86,69 @@ pub struct MinterUpdated {
     pub stablecoin: Pubkey,
     pub minter: Pubkey,
     pub new_quota: u64,
+    /// Epoch length in effect after the update (0 = lifetime quota)
+    pub epoch_duration: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterRevoked {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterQuotaExhausted {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub quota: u64,
+    pub minted_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterStatsSnapshotted {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub period: u64,
+    pub minted_amount: u64,
+    pub reset: bool,
+    pub taken_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QuotaTransferred {
+    pub stablecoin: Pubkey,
+    pub from_minter: Pubkey,
+    pub to_minter: Pubkey,
+    pub amount: u64,
+    pub from_quota: u64,
+    pub to_quota: u64,
     pub updated_by: Pubkey,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AuthorityTransferProposed {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub accept_after: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AuthorityTransferred {
     pub stablecoin: Pubkey,
@@ -97,6 +164,8 @@ pub struct AddedToBlacklist {
     pub reason: String,
     pub blacklisted_by: Pubkey,
     pub timestamp: i64,
+    pub expires_at: Option<i64>,
+    pub reason_code: BlacklistReasonCode,
 }
 
 #[event]
@@ -107,12 +176,112 @@ pub struct RemovedFromBlacklist {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BlacklistOwnerReassigned {
+    pub stablecoin: Pubkey,
+    pub address: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub reassigned_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokensSeized {
     pub stablecoin: Pubkey,
     pub from: Pubkey,
     pub to: Pubkey,
     pub amount: u64,
+    /// Portion of `amount` routed to the seizure fee treasury
+    pub fee: u64,
     pub seized_by: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ConfigUpdated {
+    pub stablecoin: Pubkey,
+    pub setting: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub stablecoin: Pubkey,
+    pub name: String,
+    pub uri: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StablecoinClosed {
+    pub stablecoin: Pubkey,
+    pub mint: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Current configuration, re-emitted on demand by `emit_config_snapshot` so
+/// indexers that missed `StablecoinInitialized` can bootstrap from the log.
+/// Carries every configurable `StablecoinState` field; compliance counters
+/// are left to `get_compliance_stats`.
+#[event]
+pub struct ConfigSnapshot {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub decimals: u8,
+    pub display_decimals: u8,
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    pub default_account_frozen: bool,
+    pub paused: bool,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub max_single_mint: u64,
+    pub seize_fee_bps: u16,
+    pub mint_lock_duration: i64,
+    pub mint_introspection: bool,
+    pub separate_freeze_authority: bool,
+    pub burn_address: Pubkey,
+    pub event_mask: u8,
+    pub pending_authority: Option<Pubkey>,
+    pub authority_transfer_cooldown: i64,
+    pub hook_mode: HookMode,
+    pub freeze_protected: Vec<Pubkey>,
+    pub max_uri_len: u16,
+    pub seize_fee_treasury: Pubkey,
+    pub max_roles_per_holder: u8,
+    pub restrict_redemptions: bool,
+    pub redemption_accounts: Vec<Pubkey>,
+    pub reserve_oracle: Option<Pubkey>,
+    pub min_reserve_ratio_bps: u16,
+    pub recovery_authority: Option<Pubkey>,
+    pub recovery_inactivity_period: i64,
+    pub require_rent_exempt_destination: bool,
+    pub max_seize_per_tx: u64,
+    pub allow_burn_when_paused: bool,
+    pub max_transfer_per_tx: u64,
+    pub min_hold_balance: u64,
+    pub denied_integrations: Vec<Pubkey>,
+    pub epoch_alignment: Option<i64>,
+    pub max_frozen_alert: u64,
+    pub freeze_config_during_transfer: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the number of frozen accounts rises above `max_frozen_alert`.
+/// Not subject to `event_mask`.
+#[event]
+pub struct MassFreezeAlert {
+    pub stablecoin: Pubkey,
+    pub frozen_count: u64,
+    pub threshold: u64,
+    pub frozen_by: Pubkey,
+    pub timestamp: i64,
+}
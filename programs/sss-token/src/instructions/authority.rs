@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::constants::*;
-use crate::events::AuthorityTransferred;
+use crate::error::StablecoinError;
+use crate::events::{AuthorityTransferProposed, AuthorityTransferCancelled, AuthorityTransferred};
 
 #[derive(Accounts)]
 pub struct TransferAuthority<'info> {
@@ -16,19 +17,142 @@ pub struct TransferAuthority<'info> {
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
-    /// CHECK: The new authority
+    /// CHECK: The proposed authority; only recorded until it accepts
     pub new_authority: AccountInfo<'info>,
 }
 
-pub fn handler(ctx: Context<TransferAuthority>) -> Result<()> {
-    let previous_authority = ctx.accounts.stablecoin.authority;
-    ctx.accounts.stablecoin.authority = ctx.accounts.new_authority.key();
+/// Step one of the authority handover: record the proposed authority and
+/// start the cooldown clock. Proposing again replaces any pending proposal.
+pub fn transfer_authority_handler(ctx: Context<TransferAuthority>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.pending_authority = Some(ctx.accounts.new_authority.key());
+    stablecoin.pending_since = now;
+    stablecoin.record_authority_action(now);
+
+    emit!(AuthorityTransferProposed {
+        stablecoin: stablecoin.key(),
+        authority: stablecoin.authority,
+        pending_authority: ctx.accounts.new_authority.key(),
+        accept_after: now.saturating_add(stablecoin.authority_transfer_cooldown),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.pending_authority == Some(new_authority.key()) @ StablecoinError::NotPendingAuthority,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+/// Step two: the proposed authority takes over once the cooldown has elapsed.
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    require!(
+        now >= stablecoin.pending_since.saturating_add(stablecoin.authority_transfer_cooldown),
+        StablecoinError::AuthorityTransferCooldown
+    );
+
+    let previous_authority = stablecoin.authority;
+    stablecoin.authority = ctx.accounts.new_authority.key();
+    stablecoin.pending_authority = None;
+    stablecoin.pending_since = 0;
+    stablecoin.record_authority_action(now);
 
     emit!(AuthorityTransferred {
-        stablecoin: ctx.accounts.stablecoin.key(),
+        stablecoin: stablecoin.key(),
         previous_authority,
         new_authority: ctx.accounts.new_authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+/// Withdraws a pending proposal, which also lifts
+/// `freeze_config_during_transfer`. Without a pending proposal this does
+/// nothing.
+pub fn cancel_authority_transfer_handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    let Some(cancelled_authority) = stablecoin.pending_authority.take() else {
+        return Ok(());
+    };
+    stablecoin.pending_since = 0;
+    stablecoin.record_authority_action(now);
+
+    emit!(AuthorityTransferCancelled {
+        stablecoin: stablecoin.key(),
+        authority: stablecoin.authority,
+        cancelled_authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRecovery<'info> {
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.recovery_authority == Some(recovery_authority.key()) @ StablecoinError::NotRecoveryAuthority,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+/// Dead-man's switch: once the authority has been inactive for the recovery
+/// period, the recovery authority takes over. Any pending handover is
+/// dropped, and the new authority has to designate its own backup.
+pub fn claim_recovery_handler(ctx: Context<ClaimRecovery>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    require!(
+        now >= stablecoin
+            .last_authority_action
+            .saturating_add(stablecoin.recovery_inactivity_period),
+        StablecoinError::AuthorityStillActive
+    );
+
+    let previous_authority = stablecoin.authority;
+    stablecoin.authority = ctx.accounts.recovery_authority.key();
+    stablecoin.pending_authority = None;
+    stablecoin.pending_since = 0;
+    stablecoin.recovery_authority = None;
+    stablecoin.recovery_inactivity_period = 0;
+    stablecoin.record_authority_action(now);
+
+    emit!(AuthorityTransferred {
+        stablecoin: stablecoin.key(),
+        previous_authority,
+        new_authority: ctx.accounts.recovery_authority.key(),
+        timestamp: now,
     });
 
     Ok(())
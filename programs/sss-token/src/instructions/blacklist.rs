@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use spl_token_2022::{extension::StateWithExtensions, state::Account as SplAccount};
 
 use crate::state::*;
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::{AddedToBlacklist, RemovedFromBlacklist};
+use crate::events::{AddedToBlacklist, BlacklistOwnerReassigned, RemovedFromBlacklist};
+use crate::instructions::freeze::freeze_token_account;
+use crate::instructions::maintenance::grow_account;
 
 #[derive(Accounts)]
 pub struct AddToBlacklist<'info> {
@@ -11,6 +15,7 @@ pub struct AddToBlacklist<'info> {
     pub blacklister: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
     )]
@@ -22,8 +27,10 @@ pub struct AddToBlacklist<'info> {
     )]
     pub role: Account<'info, RoleAccount>,
 
+    // `init_if_needed` so a repeat add reaches the handler and fails with
+    // `AlreadyBlacklisted` instead of the System program's "already in use"
     #[account(
-        init,
+        init_if_needed,
         payer = blacklister,
         space = BlacklistEntry::LEN,
         seeds = [BLACKLIST_SEED, stablecoin.key().as_ref(), address.key().as_ref()],
@@ -43,6 +50,7 @@ pub struct RemoveFromBlacklist<'info> {
     pub blacklister: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
     )]
@@ -66,43 +74,535 @@ pub struct RemoveFromBlacklist<'info> {
     pub address: AccountInfo<'info>,
 }
 
-pub fn add_handler(ctx: Context<AddToBlacklist>, reason: String) -> Result<()> {
+/// `RemoveFromBlacklist` for an entry that may not exist.
+#[derive(Accounts)]
+pub struct TryRemoveFromBlacklist<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: The address's blacklist PDA; it may be uninitialized, so it is
+    /// only decoded in the handler once it is known to exist.
+    #[account(
+        mut,
+        seeds = [BLACKLIST_SEED, stablecoin.key().as_ref(), address.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: The address being removed from blacklist
+    pub address: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ImportBlacklist<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: one writable blacklist PDA per address, in order
+}
+
+#[derive(Accounts)]
+pub struct ReassignBlacklistOwner<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [BLACKLIST_SEED, stablecoin.key().as_ref(), blacklist_entry.address.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+}
+
+/// Incident response: blacklists an address and freezes its token account in
+/// one instruction, so there is no gap between the two.
+#[derive(Accounts)]
+pub struct Lockdown<'info> {
+    #[account(mut)]
+    pub responder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), responder.key().as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    // `init_if_needed` for the same reason as `AddToBlacklist`: a live entry
+    // fails with `AlreadyBlacklisted` and a lapsed one is overwritten
+    #[account(
+        init_if_needed,
+        payer = responder,
+        space = BlacklistEntry::LEN,
+        seeds = [BLACKLIST_SEED, stablecoin.key().as_ref(), address.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// CHECK: The address being locked down
+    pub address: AccountInfo<'info>,
+
+    /// CHECK: Token-2022 mint
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Token account of `address` to freeze — validated in handler
+    #[account(mut)]
+    pub target_token_account: AccountInfo<'info>,
+
+    /// CHECK: Mint freeze authority — verified against the stored seeds
+    pub freeze_authority: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetComplianceStats<'info> {
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+/// Compliance summary returned by `get_compliance_stats`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct ComplianceStats {
+    pub blacklist_entries: u64,
+    pub total_seized: u64,
+    pub seizure_count: u64,
+}
+
+/// Outcome of an import, returned to the caller as return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct ImportBlacklistResult {
+    pub added: u32,
+    pub skipped: u32,
+}
+
+/// `expires_at` makes the entry temporary. An expired entry may be
+/// overwritten by a new add without removing it first.
+pub fn add_handler(
+    ctx: Context<AddToBlacklist>,
+    reason: String,
+    reason_code: BlacklistReasonCode,
+    expires_at: Option<i64>,
+) -> Result<()> {
     // Feature gate: only SSS-2 tokens support blacklisting
     require!(ctx.accounts.stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
     require!(ctx.accounts.role.roles.is_blacklister, StablecoinError::Unauthorized);
     require!(reason.len() <= MAX_REASON_LEN, StablecoinError::ReasonTooLong);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at.unwrap_or(i64::MAX) > now,
+        StablecoinError::InvalidBlacklistExpiry
+    );
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.blacklister.key(), now);
 
     let entry = &mut ctx.accounts.blacklist_entry;
+    let existing = entry.address != Pubkey::default();
+    require!(!existing || !entry.blacklist_active(now), StablecoinError::AlreadyBlacklisted);
     entry.stablecoin = ctx.accounts.stablecoin.key();
     entry.address = ctx.accounts.address.key();
     entry.reason = reason.clone();
-    entry.blacklisted_at = Clock::get()?.unix_timestamp;
+    entry.blacklisted_at = now;
     entry.blacklisted_by = ctx.accounts.blacklister.key();
     entry.bump = ctx.bumps.blacklist_entry;
+    entry.namespace = ctx.accounts.role.roles.blacklist_namespace;
+    entry.expires_at = expires_at;
+    entry.reason_code = reason_code;
+    if !existing {
+        ctx.accounts.stablecoin.blacklist_count += 1;
+    }
 
     emit!(AddedToBlacklist {
         stablecoin: ctx.accounts.stablecoin.key(),
         address: ctx.accounts.address.key(),
         reason,
         blacklisted_by: ctx.accounts.blacklister.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
+        expires_at,
+        reason_code,
     });
 
     Ok(())
 }
 
-pub fn remove_handler(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
+/// Blacklisters may only remove entries of their own namespace; the master
+/// authority may remove any.
+fn authorize_removal(
+    stablecoin: &StablecoinState,
+    roles: &RoleFlags,
+    blacklister: Pubkey,
+    entry_namespace: u8,
+) -> Result<()> {
     // Feature gate
-    require!(ctx.accounts.stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
-    require!(ctx.accounts.role.roles.is_blacklister, StablecoinError::Unauthorized);
+    require!(stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
+    require!(roles.is_blacklister, StablecoinError::Unauthorized);
+    require!(
+        entry_namespace == roles.blacklist_namespace || blacklister == stablecoin.authority,
+        StablecoinError::BlacklistNamespaceMismatch
+    );
+    Ok(())
+}
+
+/// Fails when the address is not blacklisted, since the entry account does
+/// not exist. Use `try_remove_handler` where that is expected.
+pub fn remove_handler(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
+    authorize_removal(
+        &ctx.accounts.stablecoin,
+        &ctx.accounts.role.roles,
+        ctx.accounts.blacklister.key(),
+        ctx.accounts.blacklist_entry.namespace,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.record_activity_by(ctx.accounts.blacklister.key(), now);
+    stablecoin.blacklist_count = stablecoin.blacklist_count.saturating_sub(1);
 
     emit!(RemovedFromBlacklist {
         stablecoin: ctx.accounts.stablecoin.key(),
         address: ctx.accounts.address.key(),
         removed_by: ctx.accounts.blacklister.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     // Account is closed via Anchor's `close = blacklister` constraint
     Ok(())
 }
+
+/// Idempotent `remove_handler`: an address that is not blacklisted is a
+/// no-op instead of an error, so bulk tooling can unblacklist without
+/// checking first. Returns whether an entry was removed.
+pub fn try_remove_handler(ctx: Context<TryRemoveFromBlacklist>) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.blacklister.key(), now);
+    let entry_info = ctx.accounts.blacklist_entry.to_account_info();
+    if entry_info.owner != ctx.program_id || entry_info.data_is_empty() {
+        require!(ctx.accounts.stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
+        require!(ctx.accounts.role.roles.is_blacklister, StablecoinError::Unauthorized);
+        return Ok(false);
+    }
+
+    let entry = BlacklistEntry::decode_any_version(&entry_info.try_borrow_data()?)?;
+    authorize_removal(
+        &ctx.accounts.stablecoin,
+        &ctx.accounts.role.roles,
+        ctx.accounts.blacklister.key(),
+        entry.namespace,
+    )?;
+
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.blacklist_count = stablecoin.blacklist_count.saturating_sub(1);
+
+    emit!(RemovedFromBlacklist {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        address: ctx.accounts.address.key(),
+        removed_by: ctx.accounts.blacklister.key(),
+        timestamp: now,
+    });
+
+    // Same steps as Anchor's `close` constraint
+    let blacklister = ctx.accounts.blacklister.to_account_info();
+    blacklister.add_lamports(entry_info.lamports())?;
+    entry_info.sub_lamports(entry_info.lamports())?;
+    entry_info.assign(&system_program::ID);
+    entry_info.realloc(0, false)?;
+    Ok(true)
+}
+
+/// Hands an open entry to a different blacklister (e.g. when an analyst
+/// leaves). Only `blacklisted_by` changes; `blacklisted_at` and the reason
+/// are kept for the audit trail.
+pub fn reassign_owner_handler(ctx: Context<ReassignBlacklistOwner>, new_owner: Pubkey) -> Result<()> {
+    require!(ctx.accounts.stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+
+    let entry = &mut ctx.accounts.blacklist_entry;
+    let previous_owner = entry.blacklisted_by;
+    entry.blacklisted_by = new_owner;
+
+    emit!(BlacklistOwnerReassigned {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        address: entry.address,
+        previous_owner,
+        new_owner,
+        reassigned_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Requires both the blacklister and pauser (freeze) roles, or the master
+/// authority.
+pub fn lockdown_handler(
+    ctx: Context<Lockdown>,
+    reason: String,
+    reason_code: BlacklistReasonCode,
+) -> Result<()> {
+    require!(ctx.accounts.stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
+    let roles = ctx.accounts.role.roles;
+    require!(
+        ctx.accounts.responder.key() == ctx.accounts.stablecoin.authority
+            || (roles.is_blacklister && roles.is_pauser),
+        StablecoinError::Unauthorized
+    );
+    require!(reason.len() <= MAX_REASON_LEN, StablecoinError::ReasonTooLong);
+
+    // Freezing some other account would leave the address itself untouched
+    let target = &ctx.accounts.target_token_account;
+    require!(
+        *target.owner == ctx.accounts.token_program.key(),
+        StablecoinError::InvalidTokenAccount
+    );
+    {
+        let target_data = target.try_borrow_data()?;
+        let target_account = StateWithExtensions::<SplAccount>::unpack(&target_data)?;
+        require!(
+            target_account.base.mint == ctx.accounts.mint.key()
+                && target_account.base.owner == ctx.accounts.address.key(),
+            StablecoinError::InvalidTokenAccount
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.responder.key(), now);
+    let entry = &mut ctx.accounts.blacklist_entry;
+    let existing = entry.address != Pubkey::default();
+    require!(!existing || !entry.blacklist_active(now), StablecoinError::AlreadyBlacklisted);
+    entry.stablecoin = ctx.accounts.stablecoin.key();
+    entry.address = ctx.accounts.address.key();
+    entry.reason = reason.clone();
+    entry.blacklisted_at = now;
+    entry.blacklisted_by = ctx.accounts.responder.key();
+    entry.bump = ctx.bumps.blacklist_entry;
+    entry.namespace = roles.blacklist_namespace;
+    entry.expires_at = None;
+    entry.reason_code = reason_code;
+    if !existing {
+        ctx.accounts.stablecoin.blacklist_count += 1;
+    }
+
+    emit!(AddedToBlacklist {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        address: ctx.accounts.address.key(),
+        reason,
+        blacklisted_by: ctx.accounts.responder.key(),
+        timestamp: now,
+        expires_at: None,
+        reason_code,
+    });
+
+    freeze_token_account(
+        ctx.program_id,
+        &mut ctx.accounts.stablecoin,
+        &ctx.accounts.mint,
+        &ctx.accounts.target_token_account,
+        &ctx.accounts.freeze_authority,
+        &ctx.accounts.token_program,
+        ctx.accounts.responder.key(),
+    )
+}
+
+/// Blacklists every address that is not already present and skips the rest,
+/// so a sanctions delta can be replayed without init collisions aborting the
+/// whole batch. Expired entries count as absent and are overwritten.
+pub fn import_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ImportBlacklist<'info>>,
+    addresses: Vec<Pubkey>,
+    reason: String,
+    reason_code: BlacklistReasonCode,
+) -> Result<ImportBlacklistResult> {
+    require!(ctx.accounts.stablecoin.is_sss2(), StablecoinError::ComplianceNotEnabled);
+    require!(ctx.accounts.role.roles.is_blacklister, StablecoinError::Unauthorized);
+    require!(reason.len() <= MAX_REASON_LEN, StablecoinError::ReasonTooLong);
+    require!(addresses.len() <= MAX_BATCH_BLACKLIST, StablecoinError::BatchTooLarge);
+    require!(
+        ctx.remaining_accounts.len() == addresses.len(),
+        StablecoinError::BatchAccountMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.blacklister.key(), now);
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    let blacklister = &ctx.accounts.blacklister;
+    let rent = Rent::get()?.minimum_balance(BlacklistEntry::LEN);
+    let mut result = ImportBlacklistResult::default();
+    let mut created: u64 = 0;
+
+    for (address, entry_info) in addresses.iter().zip(ctx.remaining_accounts.iter()) {
+        let (expected, bump) = Pubkey::find_program_address(
+            &[BLACKLIST_SEED, stablecoin_key.as_ref(), address.as_ref()],
+            ctx.program_id,
+        );
+        require!(entry_info.key() == expected, StablecoinError::BatchAccountMismatch);
+
+        // A lapsed temporary entry is overwritten, as in `add_handler`
+        let existing = entry_info.owner == ctx.program_id && !entry_info.data_is_empty();
+        if existing {
+            let entry = BlacklistEntry::decode_any_version(&entry_info.try_borrow_data()?)?;
+            if entry.blacklist_active(now) {
+                result.skipped += 1;
+                continue;
+            }
+            if entry_info.data_len() < BlacklistEntry::LEN {
+                grow_account(&ctx.accounts.system_program, blacklister, entry_info, BlacklistEntry::LEN)?;
+            }
+        } else {
+            let signer_seeds: &[&[u8]] = &[BLACKLIST_SEED, stablecoin_key.as_ref(), address.as_ref(), &[bump]];
+            create_pda(
+                &ctx.accounts.system_program,
+                blacklister,
+                entry_info,
+                BlacklistEntry::LEN,
+                rent,
+                ctx.program_id,
+                signer_seeds,
+            )?;
+            created += 1;
+        }
+
+        let entry = BlacklistEntry {
+            stablecoin: stablecoin_key,
+            address: *address,
+            reason: reason.clone(),
+            blacklisted_at: now,
+            blacklisted_by: blacklister.key(),
+            bump,
+            namespace: ctx.accounts.role.roles.blacklist_namespace,
+            expires_at: None,
+            reason_code,
+        };
+        entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+        result.added += 1;
+
+        emit!(AddedToBlacklist {
+            stablecoin: stablecoin_key,
+            address: *address,
+            reason: reason.clone(),
+            blacklisted_by: blacklister.key(),
+            timestamp: now,
+            expires_at: None,
+            reason_code,
+        });
+    }
+
+    ctx.accounts.stablecoin.blacklist_count += created;
+    Ok(result)
+}
+
+/// Allocates a program-owned PDA, tolerating accounts that were pre-funded
+/// with lamports (which would make a plain `create_account` fail).
+pub(crate) fn create_pda<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    space: usize,
+    rent: u64,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let current = target.lamports();
+    if current == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    if current < rent {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            rent - current,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate { account_to_allocate: target.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign { account_to_assign: target.clone() },
+            &[signer_seeds],
+        ),
+        owner,
+    )
+}
+
+/// Read-only: current blacklist size plus lifetime seizure totals.
+pub fn get_compliance_stats_handler(ctx: Context<GetComplianceStats>) -> Result<ComplianceStats> {
+    let stablecoin = &ctx.accounts.stablecoin;
+    Ok(ComplianceStats {
+        blacklist_entries: stablecoin.blacklist_count,
+        total_seized: stablecoin.total_seized,
+        seizure_count: stablecoin.seizure_count,
+    })
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
-use spl_token_2022::instruction as token_instruction;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::Account as SplAccount,
+};
 
 use crate::state::*;
 use crate::constants::*;
@@ -13,8 +17,9 @@ pub struct BurnTokens<'info> {
 
     #[account(
         mut,
-        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -37,10 +42,56 @@ pub struct BurnTokens<'info> {
     pub token_program: AccountInfo<'info>,
 }
 
+/// Redemption burn from a custodial vault — a token account whose owner is the
+/// stablecoin PDA — so the burner does not need to own the tokens.
+#[derive(Accounts)]
+pub struct BurnFromVault<'info> {
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), burner.key().as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: Token-2022 mint
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Vault token account owned by the stablecoin PDA — validated in handler
+    #[account(mut)]
+    pub vault_token_account: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
 pub fn handler(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
     require!(amount > 0, StablecoinError::ZeroAmount);
-    require!(!ctx.accounts.stablecoin.paused, StablecoinError::Paused);
+    require!(
+        ctx.accounts.stablecoin.is_operation_allowed(OperationKind::Burn),
+        StablecoinError::Paused
+    );
     require!(ctx.accounts.role.roles.is_burner, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.burner.key(), now);
+    require!(
+        ctx.accounts.stablecoin.can_redeem_from(&ctx.accounts.burner_token_account.key()),
+        StablecoinError::RedemptionAccountNotAllowed
+    );
+
+    // Checks-effects-interactions: update counters before the CPI and rely on
+    // transaction atomicity to roll them back if the burn fails.
+    ctx.accounts.stablecoin.record_burn(amount)?;
 
     // CPI: burn — burner signs as token account owner
     invoke(
@@ -59,20 +110,82 @@ pub fn handler(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
         ],
     )?;
 
-    // Update global stats
-    let stablecoin = &mut ctx.accounts.stablecoin;
-    stablecoin.total_burned = stablecoin
-        .total_burned
-        .checked_add(amount)
-        .ok_or(StablecoinError::MathOverflow)?;
-
-    emit!(TokensBurned {
-        stablecoin: stablecoin.key(),
-        burner: ctx.accounts.burner.key(),
-        amount,
-        total_burned: stablecoin.total_burned,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+    let stablecoin = &ctx.accounts.stablecoin;
+    if stablecoin.emits(EVENT_SUPPLY) {
+        emit!(TokensBurned {
+            stablecoin: stablecoin.key(),
+            burner: ctx.accounts.burner.key(),
+            amount,
+            total_burned: stablecoin.total_burned,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn vault_handler(ctx: Context<BurnFromVault>, amount: u64) -> Result<()> {
+    require!(amount > 0, StablecoinError::ZeroAmount);
+    require!(
+        ctx.accounts.stablecoin.is_operation_allowed(OperationKind::Burn),
+        StablecoinError::Paused
+    );
+    require!(ctx.accounts.role.roles.is_burner, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.burner.key(), now);
+    require!(
+        ctx.accounts.stablecoin.can_redeem_from(&ctx.accounts.vault_token_account.key()),
+        StablecoinError::RedemptionAccountNotAllowed
+    );
+
+    // The vault must be a Token-2022 account held by the stablecoin PDA;
+    // anything else would let a burner destroy third-party balances.
+    require!(
+        *ctx.accounts.vault_token_account.owner == ctx.accounts.token_program.key(),
+        StablecoinError::InvalidTokenAccount
+    );
+    let vault_data = ctx.accounts.vault_token_account.try_borrow_data()?;
+    let vault_account = StateWithExtensions::<SplAccount>::unpack(&vault_data)?;
+    require!(
+        vault_account.base.owner == ctx.accounts.stablecoin.key(),
+        StablecoinError::InvalidVault
+    );
+    drop(vault_data);
+
+    // Effects before interactions, as in `handler`
+    ctx.accounts.stablecoin.record_burn(amount)?;
+
+    // CPI: burn — stablecoin PDA signs as vault owner
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[ctx.accounts.stablecoin.bump]];
+
+    invoke_signed(
+        &token_instruction::burn(
+            &ctx.accounts.token_program.key(),
+            &ctx.accounts.vault_token_account.key(),
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.stablecoin.key(),
+            &[],
+            amount,
+        )?,
+        &[
+            ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.stablecoin.to_account_info(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    let stablecoin = &ctx.accounts.stablecoin;
+    if stablecoin.emits(EVENT_SUPPLY) {
+        emit!(TokensBurned {
+            stablecoin: stablecoin.key(),
+            burner: ctx.accounts.burner.key(),
+            amount,
+            total_burned: stablecoin.total_burned,
+            timestamp: now,
+        });
+    }
 
     Ok(())
 }
@@ -0,0 +1,270 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{ConfigUpdated, MetadataUpdated};
+
+/// Shared context for authority-gated configuration toggles on `StablecoinState`.
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+/// Emits `ConfigUpdated` and, since only the authority gets here, records the
+/// change as authority activity for the recovery timer.
+fn emit_config_updated(ctx: &mut Context<UpdateConfig>, setting: &str) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+    emit!(ConfigUpdated {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        setting: setting.to_string(),
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+    Ok(())
+}
+
+pub fn set_mint_introspection_handler(mut ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+    ctx.accounts.stablecoin.mint_introspection = enabled;
+    emit_config_updated(&mut ctx, "mint_introspection")
+}
+
+pub fn set_burn_address_handler(mut ctx: Context<UpdateConfig>, burn_address: Pubkey) -> Result<()> {
+    ctx.accounts.stablecoin.burn_address = burn_address;
+    emit_config_updated(&mut ctx, "burn_address")
+}
+
+pub fn set_event_mask_handler(mut ctx: Context<UpdateConfig>, event_mask: u8) -> Result<()> {
+    require!(event_mask & !EVENT_MASK_ALL == 0, StablecoinError::InvalidEventMask);
+    ctx.accounts.stablecoin.event_mask = event_mask;
+    emit_config_updated(&mut ctx, "event_mask")
+}
+
+pub fn set_max_single_mint_handler(mut ctx: Context<UpdateConfig>, max_single_mint: u64) -> Result<()> {
+    ctx.accounts.stablecoin.max_single_mint = max_single_mint;
+    emit_config_updated(&mut ctx, "max_single_mint")
+}
+
+pub fn set_allow_burn_when_paused_handler(mut ctx: Context<UpdateConfig>, allowed: bool) -> Result<()> {
+    ctx.accounts.stablecoin.allow_burn_when_paused = allowed;
+    emit_config_updated(&mut ctx, "allow_burn_when_paused")
+}
+
+pub fn set_max_seize_per_tx_handler(mut ctx: Context<UpdateConfig>, max_seize_per_tx: u64) -> Result<()> {
+    ctx.accounts.stablecoin.max_seize_per_tx = max_seize_per_tx;
+    emit_config_updated(&mut ctx, "max_seize_per_tx")
+}
+
+pub fn set_max_transfer_per_tx_handler(mut ctx: Context<UpdateConfig>, max_transfer_per_tx: u64) -> Result<()> {
+    ctx.accounts.stablecoin.max_transfer_per_tx = max_transfer_per_tx;
+    emit_config_updated(&mut ctx, "max_transfer_per_tx")
+}
+
+pub fn set_min_hold_balance_handler(mut ctx: Context<UpdateConfig>, min_hold_balance: u64) -> Result<()> {
+    ctx.accounts.stablecoin.min_hold_balance = min_hold_balance;
+    emit_config_updated(&mut ctx, "min_hold_balance")
+}
+
+pub fn set_display_decimals_handler(mut ctx: Context<UpdateConfig>, display_decimals: u8) -> Result<()> {
+    require!(
+        display_decimals <= ctx.accounts.stablecoin.decimals,
+        StablecoinError::InvalidDisplayDecimals
+    );
+    ctx.accounts.stablecoin.display_decimals = display_decimals;
+    emit_config_updated(&mut ctx, "display_decimals")
+}
+
+pub fn set_max_frozen_alert_handler(mut ctx: Context<UpdateConfig>, threshold: u64) -> Result<()> {
+    ctx.accounts.stablecoin.max_frozen_alert = threshold;
+    emit_config_updated(&mut ctx, "max_frozen_alert")
+}
+
+/// Existing epochs run out as configured; the next roll lands on a boundary.
+pub fn set_epoch_alignment_handler(mut ctx: Context<UpdateConfig>, offset: Option<i64>) -> Result<()> {
+    require!(offset.unwrap_or(0) >= 0, StablecoinError::InvalidEpochAlignment);
+    ctx.accounts.stablecoin.epoch_alignment = offset;
+    emit_config_updated(&mut ctx, "epoch_alignment")
+}
+
+pub fn set_authority_transfer_cooldown_handler(mut ctx: Context<UpdateConfig>, cooldown: i64) -> Result<()> {
+    require!(cooldown >= 0, StablecoinError::InvalidCooldown);
+    ctx.accounts.stablecoin.authority_transfer_cooldown = cooldown;
+    emit_config_updated(&mut ctx, "authority_transfer_cooldown")
+}
+
+pub fn set_mint_lock_duration_handler(mut ctx: Context<UpdateConfig>, duration: i64) -> Result<()> {
+    require!(duration >= 0, StablecoinError::InvalidMintLockDuration);
+    ctx.accounts.stablecoin.mint_lock_duration = duration;
+    emit_config_updated(&mut ctx, "mint_lock_duration")
+}
+
+pub fn set_reserve_oracle_handler(
+    mut ctx: Context<UpdateConfig>,
+    oracle: Option<Pubkey>,
+    min_reserve_ratio_bps: u16,
+) -> Result<()> {
+    // A zero ratio would make every mint pass the check while the oracle
+    // is still demanded as an account.
+    require!(
+        oracle.is_none() || min_reserve_ratio_bps > 0,
+        StablecoinError::InvalidReserveRatio
+    );
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.reserve_oracle = oracle;
+    stablecoin.min_reserve_ratio_bps = min_reserve_ratio_bps;
+    emit_config_updated(&mut ctx, "reserve_oracle")
+}
+
+pub fn set_recovery_authority_handler(
+    mut ctx: Context<UpdateConfig>,
+    recovery_authority: Option<Pubkey>,
+    inactivity_period: i64,
+) -> Result<()> {
+    require!(
+        recovery_authority.is_none() || inactivity_period > 0,
+        StablecoinError::InvalidRecoveryPeriod
+    );
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.recovery_authority = recovery_authority;
+    stablecoin.recovery_inactivity_period = inactivity_period;
+    emit_config_updated(&mut ctx, "recovery_authority")
+}
+
+/// No-op proof of life for an authority that otherwise rarely changes
+/// configuration; restarts the recovery timer.
+pub fn authority_heartbeat_handler(mut ctx: Context<UpdateConfig>) -> Result<()> {
+    emit_config_updated(&mut ctx, "heartbeat")
+}
+
+pub fn add_freeze_protected_handler(mut ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+    let protected = &mut ctx.accounts.stablecoin.freeze_protected;
+    if !protected.contains(&account) {
+        require!(protected.len() < MAX_FREEZE_PROTECTED, StablecoinError::FreezeProtectedListFull);
+        protected.push(account);
+    }
+    emit_config_updated(&mut ctx, "freeze_protected")
+}
+
+pub fn remove_freeze_protected_handler(mut ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+    ctx.accounts.stablecoin.freeze_protected.retain(|protected| *protected != account);
+    emit_config_updated(&mut ctx, "freeze_protected")
+}
+
+pub fn set_restrict_redemptions_handler(mut ctx: Context<UpdateConfig>, restrict: bool) -> Result<()> {
+    ctx.accounts.stablecoin.restrict_redemptions = restrict;
+    emit_config_updated(&mut ctx, "restrict_redemptions")
+}
+
+pub fn add_redemption_account_handler(mut ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+    let accounts = &mut ctx.accounts.stablecoin.redemption_accounts;
+    if !accounts.contains(&account) {
+        require!(accounts.len() < MAX_REDEMPTION_ACCOUNTS, StablecoinError::RedemptionListFull);
+        accounts.push(account);
+    }
+    emit_config_updated(&mut ctx, "redemption_accounts")
+}
+
+pub fn remove_redemption_account_handler(mut ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+    ctx.accounts.stablecoin.redemption_accounts.retain(|allowed| *allowed != account);
+    emit_config_updated(&mut ctx, "redemption_accounts")
+}
+
+pub fn add_denied_integration_handler(mut ctx: Context<UpdateConfig>, owner: Pubkey) -> Result<()> {
+    let denied = &mut ctx.accounts.stablecoin.denied_integrations;
+    if !denied.contains(&owner) {
+        require!(denied.len() < MAX_DENIED_INTEGRATIONS, StablecoinError::DeniedIntegrationListFull);
+        denied.push(owner);
+    }
+    emit_config_updated(&mut ctx, "denied_integrations")
+}
+
+pub fn remove_denied_integration_handler(mut ctx: Context<UpdateConfig>, owner: Pubkey) -> Result<()> {
+    ctx.accounts.stablecoin.denied_integrations.retain(|denied| *denied != owner);
+    emit_config_updated(&mut ctx, "denied_integrations")
+}
+
+pub fn set_seize_fee_handler(mut ctx: Context<UpdateConfig>, fee_bps: u16, treasury: Pubkey) -> Result<()> {
+    require!(fee_bps <= MAX_SEIZE_FEE_BPS, StablecoinError::InvalidFeeBps);
+    // The default key would make every fee-bearing seizure fail
+    require!(
+        fee_bps == 0 || treasury != Pubkey::default(),
+        StablecoinError::FeeTreasuryNotSet
+    );
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.seize_fee_bps = fee_bps;
+    stablecoin.seize_fee_treasury = treasury;
+    emit_config_updated(&mut ctx, "seize_fee")
+}
+
+pub fn set_freeze_config_during_transfer_handler(mut ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+    ctx.accounts.stablecoin.freeze_config_during_transfer = enabled;
+    emit_config_updated(&mut ctx, "freeze_config_during_transfer")
+}
+
+pub fn set_max_roles_per_holder_handler(mut ctx: Context<UpdateConfig>, max_roles: u8) -> Result<()> {
+    ctx.accounts.stablecoin.max_roles_per_holder = max_roles;
+    emit_config_updated(&mut ctx, "max_roles_per_holder")
+}
+
+pub fn set_require_rent_exempt_destination_handler(mut ctx: Context<UpdateConfig>, required: bool) -> Result<()> {
+    ctx.accounts.stablecoin.require_rent_exempt_destination = required;
+    emit_config_updated(&mut ctx, "require_rent_exempt_destination")
+}
+
+pub fn set_hook_mode_handler(mut ctx: Context<UpdateConfig>, hook_mode: HookMode) -> Result<()> {
+    ctx.accounts.stablecoin.hook_mode = hook_mode;
+    emit_config_updated(&mut ctx, "hook_mode")
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    pub updater: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), updater.key().as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+}
+
+/// Renames the token and points it at a new URI. Gated on the metadata
+/// updater role rather than the authority, so branding changes can be
+/// delegated. The symbol is fixed at init.
+pub fn update_metadata_handler(ctx: Context<UpdateMetadata>, name: String, uri: String) -> Result<()> {
+    require!(ctx.accounts.role.roles.is_metadata_updater, StablecoinError::Unauthorized);
+    require!(name.len() <= MAX_NAME_LEN, StablecoinError::NameTooLong);
+
+    let now = Clock::get()?.unix_timestamp;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    // The account was sized for `max_uri_len` at init and is never grown
+    require!(uri.len() <= stablecoin.max_uri_len as usize, StablecoinError::UriTooLong);
+    stablecoin.record_activity_by(ctx.accounts.updater.key(), now);
+    stablecoin.name = name;
+    stablecoin.uri = uri;
+
+    emit!(MetadataUpdated {
+        stablecoin: stablecoin.key(),
+        name: stablecoin.name.clone(),
+        uri: stablecoin.uri.clone(),
+        updated_by: ctx.accounts.updater.key(),
+        timestamp: now,
+    });
+    Ok(())
+}
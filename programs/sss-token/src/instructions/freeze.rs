@@ -6,15 +6,17 @@ use spl_token_2022::instruction as token_instruction;
 use crate::state::*;
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::{AccountFrozen, AccountThawed};
+use crate::events::{AccountFrozen, AccountThawed, MassFreezeAlert};
 
 #[derive(Accounts)]
 pub struct FreezeTokenAccount<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -31,6 +33,10 @@ pub struct FreezeTokenAccount<'info> {
     #[account(mut)]
     pub target_token_account: AccountInfo<'info>,
 
+    /// CHECK: Mint freeze authority — the stablecoin PDA, or the separate
+    /// ["freeze_auth", mint] PDA; verified against the stored seeds in the handler
+    pub freeze_authority: AccountInfo<'info>,
+
     /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
     #[account(address = spl_token_2022::ID)]
     pub token_program: AccountInfo<'info>,
@@ -41,8 +47,10 @@ pub struct ThawTokenAccount<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -59,6 +67,10 @@ pub struct ThawTokenAccount<'info> {
     #[account(mut)]
     pub target_token_account: AccountInfo<'info>,
 
+    /// CHECK: Mint freeze authority — the stablecoin PDA, or the separate
+    /// ["freeze_auth", mint] PDA; verified against the stored seeds in the handler
+    pub freeze_authority: AccountInfo<'info>,
+
     /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
     #[account(address = spl_token_2022::ID)]
     pub token_program: AccountInfo<'info>,
@@ -66,66 +78,131 @@ pub struct ThawTokenAccount<'info> {
 
 pub fn freeze_handler(ctx: Context<FreezeTokenAccount>) -> Result<()> {
     require!(ctx.accounts.role.roles.is_pauser, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.authority.key(), now);
+
+    freeze_token_account(
+        ctx.program_id,
+        &mut ctx.accounts.stablecoin,
+        &ctx.accounts.mint,
+        &ctx.accounts.target_token_account,
+        &ctx.accounts.freeze_authority,
+        &ctx.accounts.token_program,
+        ctx.accounts.authority.key(),
+    )
+}
 
-    // CPI: freeze_account — stablecoin PDA is the freeze authority
-    let mint_key = ctx.accounts.mint.key();
-    let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[ctx.accounts.stablecoin.bump]];
+/// Freezes `target` through whichever PDA holds the mint's freeze authority,
+/// refusing freeze-protected accounts, and counts it towards the mass-freeze
+/// alert. Shared by `freeze_account` and `lockdown`.
+pub(crate) fn freeze_token_account<'info>(
+    program_id: &Pubkey,
+    stablecoin: &mut Account<'info, StablecoinState>,
+    mint: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    freeze_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    frozen_by: Pubkey,
+) -> Result<()> {
+    require!(
+        !stablecoin.freeze_protected.contains(&target.key()),
+        StablecoinError::FreezeProtected
+    );
+
+    // CPI: freeze_account — signed by whichever PDA holds freeze authority
+    let mint_key = mint.key();
+    let (seed, bump) = stablecoin.freeze_authority_seeds();
+    let signer_seeds: &[&[u8]] = &[seed, mint_key.as_ref(), &[bump]];
+    let expected = Pubkey::create_program_address(signer_seeds, program_id)
+        .map_err(|_| StablecoinError::InvalidFreezeAuthority)?;
+    require!(
+        freeze_authority.key() == expected,
+        StablecoinError::InvalidFreezeAuthority
+    );
 
     invoke_signed(
         &token_instruction::freeze_account(
-            &ctx.accounts.token_program.key(),
-            &ctx.accounts.target_token_account.key(),
-            &ctx.accounts.mint.key(),
-            &ctx.accounts.stablecoin.key(),
+            &token_program.key(),
+            &target.key(),
+            &mint.key(),
+            &freeze_authority.key(),
             &[],
         )?,
-        &[
-            ctx.accounts.target_token_account.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.stablecoin.to_account_info(),
-        ],
+        &[target.clone(), mint.clone(), freeze_authority.clone()],
         &[signer_seeds],
     )?;
 
-    emit!(AccountFrozen {
-        stablecoin: ctx.accounts.stablecoin.key(),
-        account: ctx.accounts.target_token_account.key(),
-        frozen_by: ctx.accounts.authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+    let now = Clock::get()?.unix_timestamp;
+    if stablecoin.emits(EVENT_FREEZE) {
+        emit!(AccountFrozen {
+            stablecoin: stablecoin.key(),
+            account: target.key(),
+            frozen_by,
+            timestamp: now,
+        });
+    }
+
+    // Alert once, on the freeze that crosses the threshold
+    stablecoin.frozen_count = stablecoin.frozen_count.saturating_add(1);
+    let threshold = stablecoin.max_frozen_alert;
+    if threshold > 0 && stablecoin.frozen_count == threshold + 1 {
+        emit!(MassFreezeAlert {
+            stablecoin: stablecoin.key(),
+            frozen_count: stablecoin.frozen_count,
+            threshold,
+            frozen_by,
+            timestamp: now,
+        });
+    }
 
     Ok(())
 }
 
 pub fn thaw_handler(ctx: Context<ThawTokenAccount>) -> Result<()> {
     require!(ctx.accounts.role.roles.is_pauser, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.authority.key(), now);
 
-    // CPI: thaw_account — stablecoin PDA is the freeze authority
+    // CPI: thaw_account — signed by whichever PDA holds freeze authority
     let mint_key = ctx.accounts.mint.key();
-    let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[ctx.accounts.stablecoin.bump]];
+    let (seed, bump) = ctx.accounts.stablecoin.freeze_authority_seeds();
+    let signer_seeds: &[&[u8]] = &[seed, mint_key.as_ref(), &[bump]];
+    let expected = Pubkey::create_program_address(signer_seeds, ctx.program_id)
+        .map_err(|_| StablecoinError::InvalidFreezeAuthority)?;
+    require!(
+        ctx.accounts.freeze_authority.key() == expected,
+        StablecoinError::InvalidFreezeAuthority
+    );
 
     invoke_signed(
         &token_instruction::thaw_account(
             &ctx.accounts.token_program.key(),
             &ctx.accounts.target_token_account.key(),
             &ctx.accounts.mint.key(),
-            &ctx.accounts.stablecoin.key(),
+            &ctx.accounts.freeze_authority.key(),
             &[],
         )?,
         &[
             ctx.accounts.target_token_account.to_account_info(),
             ctx.accounts.mint.to_account_info(),
-            ctx.accounts.stablecoin.to_account_info(),
+            ctx.accounts.freeze_authority.to_account_info(),
         ],
         &[signer_seeds],
     )?;
 
-    emit!(AccountThawed {
-        stablecoin: ctx.accounts.stablecoin.key(),
-        account: ctx.accounts.target_token_account.key(),
-        thawed_by: ctx.accounts.authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+    // Thawing a default-frozen account that was never counted also lands
+    // here, hence the floor at zero
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.frozen_count = stablecoin.frozen_count.saturating_sub(1);
+
+    if ctx.accounts.stablecoin.emits(EVENT_FREEZE) {
+        emit!(AccountThawed {
+            stablecoin: ctx.accounts.stablecoin.key(),
+            account: ctx.accounts.target_token_account.key(),
+            thawed_by: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+    }
 
     Ok(())
 }
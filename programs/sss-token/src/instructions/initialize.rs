@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id,
+    spl_associated_token_account::{self, instruction::create_associated_token_account_idempotent},
+};
 use spl_token_2022::{
     extension::ExtensionType,
     extension::default_account_state::instruction as default_state_ix,
@@ -21,6 +25,27 @@ pub struct InitializeParams {
     pub enable_permanent_delegate: bool,
     pub enable_transfer_hook: bool,
     pub default_account_frozen: bool,
+    pub separate_freeze_authority: bool,
+    /// Create the authority's treasury ATA during init (thawed if accounts
+    /// default to frozen). Expects remaining accounts:
+    ///   [0] treasury ATA (mut), [1] associated token program, [2] freeze authority
+    pub create_treasury: bool,
+    /// Room reserved for `uri`, up to MAX_URI_LEN_CAP (0 = MAX_URI_LEN)
+    pub max_uri_len: u16,
+    /// Preferred display precision, at most `decimals` (None = `decimals`)
+    pub display_decimals: Option<u8>,
+    /// Grant the authority every role. When false it only receives pauser
+    /// and blacklister; minter, burner and seizer must be granted explicitly.
+    pub init_grant_all_roles: bool,
+}
+
+impl InitializeParams {
+    pub fn uri_capacity(&self) -> usize {
+        match self.max_uri_len {
+            0 => MAX_URI_LEN,
+            len => len as usize,
+        }
+    }
 }
 
 #[derive(Accounts)]
@@ -32,7 +57,7 @@ pub struct InitializeStablecoin<'info> {
     #[account(
         init,
         payer = authority,
-        space = StablecoinState::LEN,
+        space = StablecoinState::space(params.uri_capacity().min(MAX_URI_LEN_CAP)),
         seeds = [STABLECOIN_SEED, mint.key().as_ref()],
         bump,
     )]
@@ -42,7 +67,8 @@ pub struct InitializeStablecoin<'info> {
     #[account(mut)]
     pub mint: Signer<'info>,
 
-    /// The initial role account for the authority (gets all roles)
+    /// The initial role account for the authority: every role, or only pauser
+    /// and blacklister when `init_grant_all_roles` is false
     #[account(
         init,
         payer = authority,
@@ -52,6 +78,15 @@ pub struct InitializeStablecoin<'info> {
     )]
     pub authority_role: Account<'info, RoleAccount>,
 
+    /// CHECK: The authority's issuer registry PDA. Once `init_issuer_registry`
+    /// has created it, every symbol is checked against it and recorded.
+    #[account(
+        mut,
+        seeds = [ISSUER_REGISTRY_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    pub issuer_registry: AccountInfo<'info>,
+
     /// CHECK: Transfer hook program (pass system program ID if not using hooks)
     pub transfer_hook_program: AccountInfo<'info>,
 
@@ -62,11 +97,20 @@ pub struct InitializeStablecoin<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<InitializeStablecoin>, params: InitializeParams) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializeStablecoin<'info>>,
+    params: InitializeParams,
+) -> Result<()> {
     // ── 1. Validate input ──────────────────────────────────────────────
     require!(params.name.len() <= MAX_NAME_LEN, StablecoinError::NameTooLong);
     require!(params.symbol.len() <= MAX_SYMBOL_LEN, StablecoinError::SymbolTooLong);
-    require!(params.uri.len() <= MAX_URI_LEN, StablecoinError::UriTooLong);
+    require!(params.uri_capacity() <= MAX_URI_LEN_CAP, StablecoinError::UriTooLong);
+    require!(params.uri.len() <= params.uri_capacity(), StablecoinError::UriTooLong);
+    let display_decimals = params.display_decimals.unwrap_or(params.decimals);
+    require!(display_decimals <= params.decimals, StablecoinError::InvalidDisplayDecimals);
+    if ctx.accounts.issuer_registry.owner == ctx.program_id {
+        register_symbol(&ctx, &params.symbol)?;
+    }
 
     // ── 2. Determine Token-2022 extensions ─────────────────────────────
     let mut extension_types = vec![ExtensionType::MintCloseAuthority];
@@ -154,14 +198,24 @@ pub fn handler(ctx: Context<InitializeStablecoin>, params: InitializeParams) ->
     }
 
     // ── 5. Initialize the mint ─────────────────────────────────────────
-    // Both mint authority and freeze authority are the stablecoin PDA,
-    // ensuring all operations go through our program's RBAC checks.
+    // Mint authority is the stablecoin PDA. Freeze authority is the same PDA
+    // unless a separate ["freeze_auth", mint] PDA was requested. Either way
+    // all operations go through our program's RBAC checks.
+    let (freeze_authority, freeze_authority_bump) = if params.separate_freeze_authority {
+        Pubkey::find_program_address(
+            &[FREEZE_AUTHORITY_SEED, ctx.accounts.mint.key().as_ref()],
+            ctx.program_id,
+        )
+    } else {
+        (ctx.accounts.stablecoin.key(), ctx.bumps.stablecoin)
+    };
+
     invoke(
         &token_instruction::initialize_mint2(
             &ctx.accounts.token_program.key(),
             &ctx.accounts.mint.key(),
             &ctx.accounts.stablecoin.key(),
-            Some(&ctx.accounts.stablecoin.key()),
+            Some(&freeze_authority),
             params.decimals,
         )?,
         &[ctx.accounts.mint.to_account_info()],
@@ -181,22 +235,68 @@ pub fn handler(ctx: Context<InitializeStablecoin>, params: InitializeParams) ->
     stablecoin.paused = false;
     stablecoin.total_minted = 0;
     stablecoin.total_burned = 0;
+    stablecoin.mint_introspection = false;
+    stablecoin.separate_freeze_authority = params.separate_freeze_authority;
+    stablecoin.freeze_authority_bump = freeze_authority_bump;
+    stablecoin.burn_address = Pubkey::default();
+    stablecoin.event_mask = 0;
+    stablecoin.max_single_mint = 0;
+    stablecoin.pending_authority = None;
+    stablecoin.pending_since = 0;
+    stablecoin.authority_transfer_cooldown = 0;
+    stablecoin.hook_mode = HookMode::Enforce;
+    stablecoin.freeze_protected = Vec::new();
+    stablecoin.max_uri_len = params.uri_capacity() as u16;
+    stablecoin.seize_fee_bps = 0;
+    stablecoin.seize_fee_treasury = Pubkey::default();
+    stablecoin.max_roles_per_holder = 0;
+    stablecoin.restrict_redemptions = false;
+    stablecoin.redemption_accounts = Vec::new();
+    stablecoin.mint_lock_duration = 0;
+    stablecoin.blacklist_count = 0;
+    stablecoin.total_seized = 0;
+    stablecoin.seizure_count = 0;
+    stablecoin.reserve_oracle = None;
+    stablecoin.min_reserve_ratio_bps = 0;
+    stablecoin.recovery_authority = None;
+    stablecoin.recovery_inactivity_period = 0;
+    stablecoin.record_authority_action(Clock::get()?.unix_timestamp);
+    stablecoin.require_rent_exempt_destination = false;
+    stablecoin.max_seize_per_tx = 0;
+    stablecoin.allow_burn_when_paused = false;
+    stablecoin.max_transfer_per_tx = 0;
+    stablecoin.min_hold_balance = 0;
+    stablecoin.display_decimals = display_decimals;
+    stablecoin.denied_integrations = Vec::new();
+    stablecoin.epoch_alignment = None;
+    stablecoin.frozen_count = 0;
+    stablecoin.max_frozen_alert = 0;
+    stablecoin.freeze_config_during_transfer = false;
     stablecoin.bump = ctx.bumps.stablecoin;
 
-    // ── 7. Grant all roles to the initializing authority ───────────────
+    // ── 7. Optional treasury ATA ───────────────────────────────────────
+    if params.create_treasury {
+        create_treasury(&ctx)?;
+    }
+
+    // ── 8. Grant roles to the initializing authority ───────────────────
+    let stablecoin = &ctx.accounts.stablecoin;
     let role = &mut ctx.accounts.authority_role;
+    let grant_all = params.init_grant_all_roles;
     role.stablecoin = stablecoin.key();
     role.holder = ctx.accounts.authority.key();
     role.roles = RoleFlags {
-        is_minter: true,
-        is_burner: true,
+        is_minter: grant_all,
+        is_burner: grant_all,
         is_pauser: true,
         is_blacklister: true,
-        is_seizer: true,
+        is_seizer: grant_all,
+        blacklist_namespace: 0,
+        is_metadata_updater: grant_all,
     };
     role.bump = ctx.bumps.authority_role;
 
-    // ── 8. Emit audit event ────────────────────────────────────────────
+    // ── 9. Emit audit event ────────────────────────────────────────────
     emit!(StablecoinInitialized {
         stablecoin: stablecoin.key(),
         mint: ctx.accounts.mint.key(),
@@ -204,8 +304,82 @@ pub fn handler(ctx: Context<InitializeStablecoin>, params: InitializeParams) ->
         name: params.name,
         symbol: params.symbol,
         is_sss2: stablecoin.is_sss2(),
+        decimals: params.decimals,
+        display_decimals,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
     Ok(())
 }
+
+/// Creates the authority's ATA and, when new accounts default to frozen,
+/// thaws it so the issuer can mint into it straight away.
+fn create_treasury<'info>(ctx: &Context<'_, '_, 'info, 'info, InitializeStablecoin<'info>>) -> Result<()> {
+    let [treasury, ata_program, freeze_authority, ..] = ctx.remaining_accounts else {
+        return err!(StablecoinError::TreasuryAccountsMissing);
+    };
+    require!(
+        ata_program.key() == spl_associated_token_account::ID,
+        StablecoinError::TreasuryAccountsMissing
+    );
+    let authority = ctx.accounts.authority.key();
+    let mint = ctx.accounts.mint.key();
+    let token_program = ctx.accounts.token_program.key();
+    require!(
+        treasury.key() == get_associated_token_address_with_program_id(&authority, &mint, &token_program),
+        StablecoinError::TreasuryAccountsMissing
+    );
+
+    invoke(
+        &create_associated_token_account_idempotent(&authority, &authority, &mint, &token_program),
+        &[
+            ctx.accounts.authority.to_account_info(),
+            treasury.clone(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ata_program.clone(),
+        ],
+    )?;
+
+    if !ctx.accounts.stablecoin.default_account_frozen {
+        return Ok(());
+    }
+
+    let (seed, bump) = ctx.accounts.stablecoin.freeze_authority_seeds();
+    let signer_seeds: &[&[u8]] = &[seed, mint.as_ref(), &[bump]];
+    let expected = Pubkey::create_program_address(signer_seeds, ctx.program_id)
+        .map_err(|_| StablecoinError::InvalidFreezeAuthority)?;
+    require!(freeze_authority.key() == expected, StablecoinError::InvalidFreezeAuthority);
+
+    invoke_signed(
+        &token_instruction::thaw_account(&token_program, &treasury.key(), &mint, &expected, &[])?,
+        &[
+            treasury.clone(),
+            ctx.accounts.mint.to_account_info(),
+            freeze_authority.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Records the symbol in the authority's issuer registry, failing if the
+/// authority has already issued a token with it.
+fn register_symbol<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, InitializeStablecoin<'info>>,
+    symbol: &str,
+) -> Result<()> {
+    let info = &ctx.accounts.issuer_registry;
+    let mut data = info.try_borrow_mut_data()?;
+    let mut registry = IssuerRegistry::try_deserialize(&mut &data[..])
+        .map_err(|_| StablecoinError::IssuerRegistryMissing)?;
+    require!(
+        registry.authority == ctx.accounts.authority.key(),
+        StablecoinError::IssuerRegistryMissing
+    );
+
+    registry.register(symbol)?;
+    registry.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,554 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program;
+use spl_token_2022::{
+    extension::{
+        mint_close_authority::MintCloseAuthority, permanent_delegate::PermanentDelegate,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    instruction as token_instruction,
+    state::Mint,
+};
+
+use crate::state::*;
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{ConfigSnapshot, StablecoinClosed};
+
+/// Maintenance context. The stablecoin PDA is resolved with the canonical
+/// bump rather than the stored one, so a corrupted `bump` cannot lock the
+/// repair path out.
+#[derive(Accounts)]
+pub struct RepairBump<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+    // remaining_accounts: writable RoleAccount / MinterInfo / BlacklistEntry
+    // PDAs of this stablecoin whose stored bump should be checked, already
+    // migrated to the current layout
+}
+
+/// Re-derives the canonical bump of the stablecoin PDA (and its separate
+/// freeze authority, if any) plus every PDA passed in remaining accounts,
+/// rewriting any stored bump that differs. Returns the number of bumps fixed.
+pub fn repair_bump_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RepairBump<'info>>,
+) -> Result<u8> {
+    let program_id = ctx.program_id;
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    let mut repaired: u8 = 0;
+
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.record_authority_action(Clock::get()?.unix_timestamp);
+    if stablecoin.bump != ctx.bumps.stablecoin {
+        stablecoin.bump = ctx.bumps.stablecoin;
+        repaired += 1;
+    }
+    if stablecoin.separate_freeze_authority {
+        let (_, bump) = Pubkey::find_program_address(
+            &[FREEZE_AUTHORITY_SEED, stablecoin.mint.as_ref()],
+            program_id,
+        );
+        if stablecoin.freeze_authority_bump != bump {
+            stablecoin.freeze_authority_bump = bump;
+            repaired += 1;
+        }
+    }
+
+    for info in ctx.remaining_accounts.iter() {
+        let fixed = if let Ok(mut role) = Account::<RoleAccount>::try_from(info) {
+            let seeds: &[&[u8]] = &[ROLE_SEED, stablecoin_key.as_ref(), role.holder.as_ref()];
+            let canonical = canonical_bump(info.key, seeds, program_id)?;
+            let changed = role.bump != canonical;
+            role.bump = canonical;
+            role.exit(program_id)?;
+            changed
+        } else if let Ok(mut minter_info) = Account::<MinterInfo>::try_from(info) {
+            let seeds: &[&[u8]] = &[MINTER_SEED, stablecoin_key.as_ref(), minter_info.minter.as_ref()];
+            let canonical = canonical_bump(info.key, seeds, program_id)?;
+            let changed = minter_info.bump != canonical;
+            minter_info.bump = canonical;
+            minter_info.exit(program_id)?;
+            changed
+        } else if let Ok(mut entry) = Account::<BlacklistEntry>::try_from(info) {
+            let seeds: &[&[u8]] = &[BLACKLIST_SEED, stablecoin_key.as_ref(), entry.address.as_ref()];
+            let canonical = canonical_bump(info.key, seeds, program_id)?;
+            let changed = entry.bump != canonical;
+            entry.bump = canonical;
+            entry.exit(program_id)?;
+            changed
+        } else {
+            return err!(StablecoinError::NotRepairable);
+        };
+        if fixed {
+            repaired += 1;
+        }
+    }
+
+    Ok(repaired)
+}
+
+/// Canonical bump for `seeds`, after checking the account actually lives at
+/// that address.
+fn canonical_bump(key: &Pubkey, seeds: &[&[u8]], program_id: &Pubkey) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, program_id);
+    require!(*key == expected, StablecoinError::NotRepairable);
+    Ok(bump)
+}
+
+#[derive(Accounts)]
+pub struct MigrateStablecoin<'info> {
+    /// Pays for the extra rent of the larger layout
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Decoded by hand because the original layout does not
+    /// deserialize as `StablecoinState`; ownership, discriminator, PDA and
+    /// authority are verified below.
+    #[account(mut, owner = crate::ID)]
+    pub stablecoin: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a stablecoin account written with the original layout to the
+/// current size. Settings it did not have take their `initialize` defaults.
+/// Accounts that are already current are left untouched.
+pub fn migrate_stablecoin_handler(ctx: Context<MigrateStablecoin>) -> Result<()> {
+    let stablecoin_info = ctx.accounts.stablecoin.to_account_info();
+    let mut stablecoin = StablecoinState::decode_any_version(&stablecoin_info.try_borrow_data()?)?;
+
+    require!(
+        stablecoin.authority == ctx.accounts.authority.key(),
+        StablecoinError::Unauthorized
+    );
+    let expected = Pubkey::create_program_address(
+        &[STABLECOIN_SEED, stablecoin.mint.as_ref(), &[stablecoin.bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| StablecoinError::NotRepairable)?;
+    require!(expected == stablecoin_info.key(), StablecoinError::NotRepairable);
+
+    if stablecoin_info.data_len() != StablecoinState::LEGACY_LEN {
+        return Ok(());
+    }
+
+    stablecoin.record_authority_action(Clock::get()?.unix_timestamp);
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority,
+        &stablecoin_info,
+        StablecoinState::LEN,
+    )?;
+    let mut data = stablecoin_info.try_borrow_mut_data()?;
+    stablecoin.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateRoleAccount<'info> {
+    /// Pays for the extra rent of the larger layout
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Decoded by hand because a legacy layout does not deserialize
+    /// as `RoleAccount`; ownership, discriminator and PDA are verified below.
+    #[account(mut, owner = crate::ID)]
+    pub role: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a role account written with an older, shorter `RoleFlags` layout to
+/// the current size. Flags it did not have default to false. Accounts that
+/// are already current are left untouched.
+pub fn migrate_role_account_handler(ctx: Context<MigrateRoleAccount>) -> Result<()> {
+    ctx.accounts
+        .stablecoin
+        .record_authority_action(Clock::get()?.unix_timestamp);
+    let role_info = ctx.accounts.role.to_account_info();
+    let role = RoleAccount::decode_any_version(&role_info.try_borrow_data()?)?;
+
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    require!(role.stablecoin == stablecoin_key, StablecoinError::NotRepairable);
+    let expected = Pubkey::create_program_address(
+        &[ROLE_SEED, stablecoin_key.as_ref(), role.holder.as_ref(), &[role.bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| StablecoinError::NotRepairable)?;
+    require!(expected == role_info.key(), StablecoinError::NotRepairable);
+
+    if role_info.data_len() == RoleAccount::LEN {
+        return Ok(());
+    }
+
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority,
+        &role_info,
+        RoleAccount::LEN,
+    )?;
+    let mut data = role_info.try_borrow_mut_data()?;
+    role.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateMinterInfo<'info> {
+    /// Pays for the extra rent of the larger layout
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Decoded by hand because a legacy layout does not deserialize
+    /// as `MinterInfo`; ownership, discriminator and PDA are verified below.
+    #[account(mut, owner = crate::ID)]
+    pub minter_info: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a minter account written before quota epochs to the current size.
+/// The epoch fields start at zero, so the minter keeps its lifetime quota.
+/// Accounts that are already current are left untouched.
+pub fn migrate_minter_info_handler(ctx: Context<MigrateMinterInfo>) -> Result<()> {
+    ctx.accounts
+        .stablecoin
+        .record_authority_action(Clock::get()?.unix_timestamp);
+    let minter_info = ctx.accounts.minter_info.to_account_info();
+    let info = MinterInfo::decode_any_version(&minter_info.try_borrow_data()?)?;
+
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    require!(info.stablecoin == stablecoin_key, StablecoinError::NotRepairable);
+    let expected = Pubkey::create_program_address(
+        &[MINTER_SEED, stablecoin_key.as_ref(), info.minter.as_ref(), &[info.bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| StablecoinError::NotRepairable)?;
+    require!(expected == minter_info.key(), StablecoinError::NotRepairable);
+
+    if minter_info.data_len() == MinterInfo::LEN {
+        return Ok(());
+    }
+
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority,
+        &minter_info,
+        MinterInfo::LEN,
+    )?;
+    let mut data = minter_info.try_borrow_mut_data()?;
+    info.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateBlacklistEntry<'info> {
+    /// Pays for the extra rent of the larger layout
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Decoded by hand because a legacy layout may not deserialize as
+    /// `BlacklistEntry`; ownership, discriminator and PDA are verified below.
+    #[account(mut, owner = crate::ID)]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a blacklist entry written before `namespace`, `expires_at` and
+/// `reason_code` existed to the current size. The missing fields read as
+/// namespace 0, no expiry and `Other`. Entries that are already current are
+/// left untouched.
+pub fn migrate_blacklist_entry_handler(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+    ctx.accounts
+        .stablecoin
+        .record_authority_action(Clock::get()?.unix_timestamp);
+    let entry_info = ctx.accounts.blacklist_entry.to_account_info();
+    let entry = BlacklistEntry::decode_any_version(&entry_info.try_borrow_data()?)?;
+
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    require!(entry.stablecoin == stablecoin_key, StablecoinError::NotRepairable);
+    let expected = Pubkey::create_program_address(
+        &[BLACKLIST_SEED, stablecoin_key.as_ref(), entry.address.as_ref(), &[entry.bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| StablecoinError::NotRepairable)?;
+    require!(expected == entry_info.key(), StablecoinError::NotRepairable);
+
+    if entry_info.data_len() == BlacklistEntry::LEN {
+        return Ok(());
+    }
+
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.authority,
+        &entry_info,
+        BlacklistEntry::LEN,
+    )?;
+    let mut data = entry_info.try_borrow_mut_data()?;
+    entry.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+/// Reallocates a program-owned account to `len` bytes (zero-filling the new
+/// tail), with `payer` topping up the rent.
+pub(crate) fn grow_account<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(len);
+    let shortfall = rent.saturating_sub(target.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    target.realloc(len, true)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckAuthorities<'info> {
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint, matched against the stablecoin above
+    pub mint: AccountInfo<'info>,
+}
+
+/// Read-only health check: returns an AUTHORITY_* bitfield of mint
+/// authorities that have drifted away from this program's PDAs (0 = all
+/// controlled). The permanent delegate is only checked on SSS-2 mints.
+pub fn check_authorities_handler(ctx: Context<CheckAuthorities>) -> Result<u8> {
+    let stablecoin = &ctx.accounts.stablecoin;
+    let stablecoin_key = stablecoin.key();
+    let (seed, bump) = stablecoin.freeze_authority_seeds();
+    let freeze_authority = Pubkey::create_program_address(
+        &[seed, stablecoin.mint.as_ref(), &[bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| StablecoinError::InvalidFreezeAuthority)?;
+
+    let data = ctx.accounts.mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    let mut diverged = 0;
+
+    if mint.base.mint_authority != COption::Some(stablecoin_key) {
+        diverged |= AUTHORITY_MINT;
+    }
+    if mint.base.freeze_authority != COption::Some(freeze_authority) {
+        diverged |= AUTHORITY_FREEZE;
+    }
+    let close_authority = mint
+        .get_extension::<MintCloseAuthority>()
+        .ok()
+        .and_then(|ext| Option::<Pubkey>::from(ext.close_authority));
+    if close_authority != Some(stablecoin_key) {
+        diverged |= AUTHORITY_CLOSE;
+    }
+    if stablecoin.enable_permanent_delegate {
+        let delegate = mint
+            .get_extension::<PermanentDelegate>()
+            .ok()
+            .and_then(|ext| Option::<Pubkey>::from(ext.delegate));
+        if delegate != Some(stablecoin_key) {
+            diverged |= AUTHORITY_PERMANENT_DELEGATE;
+        }
+    }
+
+    Ok(diverged)
+}
+
+/// Decommissions a stablecoin: closes the mint (the stablecoin PDA is its
+/// close authority) and the state account, refunding both to the authority.
+/// Role, minter and blacklist PDAs are left in place.
+#[derive(Accounts)]
+pub struct CloseStablecoin<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint, matched against the stablecoin above
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+pub fn close_stablecoin_handler(ctx: Context<CloseStablecoin>) -> Result<()> {
+    require!(ctx.accounts.stablecoin.is_paused(), StablecoinError::NotPaused);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+
+    // The mint's supply, not total_minted - total_burned: seizures move
+    // tokens without burning them, and Token-2022 refuses to close a mint
+    // with supply anyway
+    {
+        let data = ctx.accounts.mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+        require!(mint.base.supply == 0, StablecoinError::SupplyNotZero);
+    }
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[ctx.accounts.stablecoin.bump]];
+    invoke_signed(
+        &token_instruction::close_account(
+            &ctx.accounts.token_program.key(),
+            &mint_key,
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.stablecoin.key(),
+            &[],
+        )?,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.stablecoin.to_account_info(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    emit!(StablecoinClosed {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        mint: mint_key,
+        closed_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EmitConfigSnapshot<'info> {
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct GetCirculatingSupply<'info> {
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+/// Read-only: returns `total_minted - total_burned` as `u64` return data.
+/// Unlike the mint's supply, seizures do not move it.
+pub fn get_circulating_supply_handler(ctx: Context<GetCirculatingSupply>) -> Result<u64> {
+    ctx.accounts.stablecoin.circulating_supply()
+}
+
+/// Permissionless: re-emits the current configuration as `ConfigSnapshot`.
+pub fn emit_config_snapshot_handler(ctx: Context<EmitConfigSnapshot>) -> Result<()> {
+    let stablecoin = &ctx.accounts.stablecoin;
+    emit!(ConfigSnapshot {
+        stablecoin: stablecoin.key(),
+        authority: stablecoin.authority,
+        mint: stablecoin.mint,
+        name: stablecoin.name.clone(),
+        symbol: stablecoin.symbol.clone(),
+        uri: stablecoin.uri.clone(),
+        decimals: stablecoin.decimals,
+        display_decimals: stablecoin.display_decimals,
+        enable_permanent_delegate: stablecoin.enable_permanent_delegate,
+        enable_transfer_hook: stablecoin.enable_transfer_hook,
+        default_account_frozen: stablecoin.default_account_frozen,
+        paused: stablecoin.paused,
+        total_minted: stablecoin.total_minted,
+        total_burned: stablecoin.total_burned,
+        max_single_mint: stablecoin.max_single_mint,
+        seize_fee_bps: stablecoin.seize_fee_bps,
+        mint_lock_duration: stablecoin.mint_lock_duration,
+        mint_introspection: stablecoin.mint_introspection,
+        separate_freeze_authority: stablecoin.separate_freeze_authority,
+        burn_address: stablecoin.burn_address,
+        event_mask: stablecoin.event_mask,
+        pending_authority: stablecoin.pending_authority,
+        authority_transfer_cooldown: stablecoin.authority_transfer_cooldown,
+        hook_mode: stablecoin.hook_mode,
+        freeze_protected: stablecoin.freeze_protected.clone(),
+        max_uri_len: stablecoin.max_uri_len,
+        seize_fee_treasury: stablecoin.seize_fee_treasury,
+        max_roles_per_holder: stablecoin.max_roles_per_holder,
+        restrict_redemptions: stablecoin.restrict_redemptions,
+        redemption_accounts: stablecoin.redemption_accounts.clone(),
+        reserve_oracle: stablecoin.reserve_oracle,
+        min_reserve_ratio_bps: stablecoin.min_reserve_ratio_bps,
+        recovery_authority: stablecoin.recovery_authority,
+        recovery_inactivity_period: stablecoin.recovery_inactivity_period,
+        require_rent_exempt_destination: stablecoin.require_rent_exempt_destination,
+        max_seize_per_tx: stablecoin.max_seize_per_tx,
+        allow_burn_when_paused: stablecoin.allow_burn_when_paused,
+        max_transfer_per_tx: stablecoin.max_transfer_per_tx,
+        min_hold_balance: stablecoin.min_hold_balance,
+        denied_integrations: stablecoin.denied_integrations.clone(),
+        epoch_alignment: stablecoin.epoch_alignment,
+        max_frozen_alert: stablecoin.max_frozen_alert,
+        freeze_config_during_transfer: stablecoin.freeze_config_during_transfer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
-use spl_token_2022::instruction as token_instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::{self as token_instruction, TokenInstruction},
+    state::{Account as SplAccount, Mint},
+};
 
 use crate::state::*;
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::TokensMinted;
+use crate::events::{MinterQuotaExhausted, TokensMinted};
+use crate::instructions::blacklist::create_pda;
 
 #[derive(Accounts)]
 pub struct MintTokens<'info> {
@@ -13,8 +21,9 @@ pub struct MintTokens<'info> {
 
     #[account(
         mut,
-        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -44,18 +53,104 @@ pub struct MintTokens<'info> {
     pub token_program: AccountInfo<'info>,
 }
 
-pub fn handler(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintTokens<'info>>,
+    amount: u64,
+    reference: Option<[u8; 16]>,
+) -> Result<()> {
     require!(amount > 0, StablecoinError::ZeroAmount);
-    require!(!ctx.accounts.stablecoin.paused, StablecoinError::Paused);
+    let max_single_mint = ctx.accounts.stablecoin.max_single_mint;
+    require!(
+        max_single_mint == 0 || amount <= max_single_mint,
+        StablecoinError::MintAmountTooLarge
+    );
+    require!(
+        ctx.accounts.stablecoin.is_operation_allowed(OperationKind::Mint),
+        StablecoinError::Paused
+    );
     require!(ctx.accounts.role.roles.is_minter, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.minter.key(), now);
 
-    // Enforce per-minter quota
+    // Fail with a program error up front instead of an opaque CPI error
+    let recipient = &ctx.accounts.recipient_token_account;
+    require!(
+        *recipient.owner == ctx.accounts.token_program.key(),
+        StablecoinError::InvalidTokenAccount
+    );
+    {
+        let recipient_data = recipient.try_borrow_data()?;
+        let recipient_account = StateWithExtensions::<SplAccount>::unpack(&recipient_data)?;
+        require!(
+            recipient_account.base.mint == ctx.accounts.mint.key(),
+            StablecoinError::RecipientMintMismatch
+        );
+    }
+
+    // With a mint lock the recipient's lock PDA and the System program come
+    // first, then the reserve oracle if one is configured; anything after
+    // them belongs to the introspection check.
+    let remaining_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let (lock_accounts, remaining_accounts) = if ctx.accounts.stablecoin.mint_lock_duration > 0 {
+        require!(remaining_accounts.len() >= 2, StablecoinError::MintLockAccountMissing);
+        remaining_accounts.split_at(2)
+    } else {
+        (&remaining_accounts[..0], remaining_accounts)
+    };
+    let remaining_accounts = if let Some(oracle) = ctx.accounts.stablecoin.reserve_oracle {
+        let (oracle_info, rest) = remaining_accounts
+            .split_first()
+            .ok_or(StablecoinError::ReserveOracleMissing)?;
+        require!(oracle_info.key() == oracle, StablecoinError::ReserveOracleMissing);
+        check_reserve_ratio(
+            &ctx.accounts.mint,
+            oracle_info,
+            ctx.accounts.stablecoin.min_reserve_ratio_bps,
+            amount,
+        )?;
+        rest
+    } else {
+        remaining_accounts
+    };
+
+    if ctx.accounts.stablecoin.mint_introspection {
+        check_bundled_transfers(
+            &ctx.accounts.stablecoin.key(),
+            &ctx.accounts.mint.key(),
+            remaining_accounts,
+        )?;
+    }
+
+    // Enforce per-minter quota (per epoch when the minter has one)
+    let epoch_alignment = ctx.accounts.stablecoin.epoch_alignment;
     let minter_info = &mut ctx.accounts.minter_info;
-    let new_minted = minter_info
+    minter_info.roll_epoch(now, epoch_alignment);
+    let new_used = minter_info
+        .quota_used()
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    require!(new_used <= minter_info.quota, StablecoinError::QuotaExceeded);
+
+    // Checks-effects-interactions: update counters before the CPI and rely on
+    // transaction atomicity to roll them back if the mint fails.
+    minter_info.minted_amount = minter_info
         .minted_amount
         .checked_add(amount)
         .ok_or(StablecoinError::MathOverflow)?;
-    require!(new_minted <= minter_info.quota, StablecoinError::QuotaExceeded);
+    minter_info.minted_this_epoch = minter_info
+        .minted_this_epoch
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.total_minted = stablecoin
+        .total_minted
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    if !lock_accounts.is_empty() {
+        record_mint_lock(&ctx, lock_accounts, now)?;
+    }
 
     // CPI: mint_to via stablecoin PDA (mint authority)
     let mint_key = ctx.accounts.mint.key();
@@ -78,24 +173,190 @@ pub fn handler(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
         &[signer_seeds],
     )?;
 
-    // Update quota tracking
-    minter_info.minted_amount = new_minted;
+    let stablecoin = &ctx.accounts.stablecoin;
+    if stablecoin.emits(EVENT_SUPPLY) {
+        emit!(TokensMinted {
+            stablecoin: stablecoin.key(),
+            minter: ctx.accounts.minter.key(),
+            recipient: ctx.accounts.recipient_token_account.key(),
+            amount,
+            total_minted: stablecoin.total_minted,
+            timestamp: now,
+            reference,
+        });
+    }
 
-    // Update global stats
-    let stablecoin = &mut ctx.accounts.stablecoin;
-    stablecoin.total_minted = stablecoin
-        .total_minted
-        .checked_add(amount)
-        .ok_or(StablecoinError::MathOverflow)?;
+    // Proactive signal for dashboards: this mint used up the last of the quota
+    let minter_info = &ctx.accounts.minter_info;
+    if minter_info.quota_used() == minter_info.quota && stablecoin.emits(EVENT_MINTER) {
+        emit!(MinterQuotaExhausted {
+            stablecoin: stablecoin.key(),
+            minter: ctx.accounts.minter.key(),
+            quota: minter_info.quota,
+            minted_amount: minter_info.minted_amount,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Rejects the mint if supply after it would exceed what the declared
+/// reserves cover at `min_reserve_ratio_bps`. The oracle account holds the
+/// reserves as a little-endian u64 at offset 0, in the mint's base units.
+fn check_reserve_ratio(
+    mint: &AccountInfo,
+    oracle: &AccountInfo,
+    min_reserve_ratio_bps: u16,
+    amount: u64,
+) -> Result<()> {
+    let reserves = {
+        let data = oracle.try_borrow_data()?;
+        let bytes: [u8; 8] = data
+            .get(..8)
+            .and_then(|b| b.try_into().ok())
+            .ok_or(StablecoinError::InvalidReserveOracle)?;
+        u64::from_le_bytes(bytes)
+    };
+    let supply = {
+        let data = mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&data)?.base.supply
+    };
+    let new_supply = supply.checked_add(amount).ok_or(StablecoinError::MathOverflow)?;
+
+    // reserves / new_supply >= ratio / 10_000, kept in integers
+    require!(
+        new_supply as u128 * min_reserve_ratio_bps as u128 <= reserves as u128 * 10_000,
+        StablecoinError::ReserveRatioBreached
+    );
+    Ok(())
+}
+
+/// Restarts the holding period of the recipient token account, creating its
+/// `MintLock` PDA on first use (paid by the minter).
+///
+/// Expected lock accounts:
+///   [0]  MintLock PDA of the recipient token account (writable)
+///   [1]  System program
+fn record_mint_lock<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, MintTokens<'info>>,
+    lock_accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<()> {
+    let stablecoin = &ctx.accounts.stablecoin;
+    let stablecoin_key = stablecoin.key();
+    let token_account = ctx.accounts.recipient_token_account.key();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[MINT_LOCK_SEED, stablecoin_key.as_ref(), token_account.as_ref()],
+        ctx.program_id,
+    );
+    let lock_info = &lock_accounts[0];
+    require!(lock_info.key() == expected, StablecoinError::MintLockAccountMissing);
+
+    if lock_info.owner != ctx.program_id || lock_info.data_is_empty() {
+        let system_program = Program::<System>::try_from(&lock_accounts[1])?;
+        let signer_seeds: &[&[u8]] = &[MINT_LOCK_SEED, stablecoin_key.as_ref(), token_account.as_ref(), &[bump]];
+        create_pda(
+            &system_program,
+            &ctx.accounts.minter,
+            lock_info,
+            MintLock::LEN,
+            Rent::get()?.minimum_balance(MintLock::LEN),
+            ctx.program_id,
+            signer_seeds,
+        )?;
+    }
+
+    let lock = MintLock {
+        stablecoin: stablecoin_key,
+        token_account,
+        last_mint_at: now,
+        locked_until: now.saturating_add(stablecoin.mint_lock_duration),
+        bump,
+    };
+    lock.try_serialize(&mut &mut lock_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Best-effort guard against minting and immediately moving funds to a
+/// blacklisted party in the same transaction. Scans the instructions after
+/// this one for Token-2022 `Transfer` / `TransferChecked` of this mint and
+/// rejects the mint if any destination owner has a blacklist entry.
+///
+/// Expected remaining accounts:
+///   [0]  Instructions sysvar
+///   [1..] for every bundled transfer: the destination token account and the
+///         blacklist PDA of its owner (any order)
+///
+/// Transfers issued through other programs (CPI) are not visible in the
+/// instruction list, so this complements the transfer hook rather than
+/// replacing it.
+fn check_bundled_transfers(
+    stablecoin: &Pubkey,
+    mint: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let (instructions_sysvar, candidates) = remaining_accounts
+        .split_first()
+        .ok_or(StablecoinError::IntrospectionAccountMissing)?;
+    let find = |key: &Pubkey| {
+        candidates
+            .iter()
+            .find(|info| info.key == key)
+            .ok_or(StablecoinError::IntrospectionAccountMissing)
+    };
+
+    let mut index = load_current_index_checked(instructions_sysvar)? as usize + 1;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        index += 1;
+        if ix.program_id != spl_token_2022::ID {
+            continue;
+        }
+
+        // Transfer: [source, destination, authority]
+        // TransferChecked: [source, mint, destination, authority]
+        let destination = match TokenInstruction::unpack(&ix.data) {
+            // Deprecated in favour of TransferChecked, but still executable
+            #[allow(deprecated)]
+            Ok(TokenInstruction::Transfer { .. }) => ix.accounts.get(1),
+            Ok(TokenInstruction::TransferChecked { .. }) => {
+                if ix.accounts.get(1).map(|meta| meta.pubkey) != Some(*mint) {
+                    continue;
+                }
+                ix.accounts.get(2)
+            }
+            _ => continue,
+        };
+        let destination = destination
+            .ok_or(StablecoinError::IntrospectionAccountMissing)?
+            .pubkey;
+
+        let destination_info = find(&destination)?;
+        require!(
+            *destination_info.owner == spl_token_2022::ID,
+            StablecoinError::InvalidTokenAccount
+        );
+        let destination_data = destination_info.try_borrow_data()?;
+        let destination_account = StateWithExtensions::<SplAccount>::unpack(&destination_data)?;
+        if destination_account.base.mint != *mint {
+            continue;
+        }
 
-    emit!(TokensMinted {
-        stablecoin: stablecoin.key(),
-        minter: ctx.accounts.minter.key(),
-        recipient: ctx.accounts.recipient_token_account.key(),
-        amount,
-        total_minted: stablecoin.total_minted,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+        let (blacklist_key, _) = Pubkey::find_program_address(
+            &[BLACKLIST_SEED, stablecoin.as_ref(), destination_account.base.owner.as_ref()],
+            &crate::ID,
+        );
+        let blacklist_entry = find(&blacklist_key)?;
+        if blacklist_entry.data_is_empty() || blacklist_entry.lamports() == 0 {
+            continue;
+        }
+        // Lapsed temporary entries no longer block, as in the transfer hook
+        let active = match BlacklistEntry::decode_any_version(&blacklist_entry.try_borrow_data()?) {
+            Ok(entry) => entry.blacklist_active(Clock::get()?.unix_timestamp),
+            Err(_) => true,
+        };
+        require!(!active, StablecoinError::Blacklisted);
+    }
 
     Ok(())
 }
@@ -7,6 +7,9 @@ pub mod roles;
 pub mod authority;
 pub mod blacklist;
 pub mod seize;
+pub mod config;
+pub mod maintenance;
+pub mod registry;
 
 pub use initialize::*;
 pub use mint::*;
@@ -17,3 +20,6 @@ pub use roles::*;
 pub use authority::*;
 pub use blacklist::*;
 pub use seize::*;
+pub use config::*;
+pub use maintenance::*;
+pub use registry::*;
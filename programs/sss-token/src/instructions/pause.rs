@@ -25,13 +25,15 @@ pub struct PauseUnpause<'info> {
 
 pub fn pause_handler(ctx: Context<PauseUnpause>) -> Result<()> {
     require!(ctx.accounts.role.roles.is_pauser, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.authority.key(), now);
 
     ctx.accounts.stablecoin.paused = true;
 
     emit!(StablecoinPaused {
         stablecoin: ctx.accounts.stablecoin.key(),
         paused_by: ctx.accounts.authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
@@ -39,13 +41,15 @@ pub fn pause_handler(ctx: Context<PauseUnpause>) -> Result<()> {
 
 pub fn unpause_handler(ctx: Context<PauseUnpause>) -> Result<()> {
     require!(ctx.accounts.role.roles.is_pauser, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.authority.key(), now);
 
     ctx.accounts.stablecoin.paused = false;
 
     emit!(StablecoinUnpaused {
         stablecoin: ctx.accounts.stablecoin.key(),
         unpaused_by: ctx.accounts.authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
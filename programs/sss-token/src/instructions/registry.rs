@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct InitIssuerRegistry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = IssuerRegistry::LEN,
+        seeds = [ISSUER_REGISTRY_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    pub registry: Account<'info, IssuerRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_issuer_registry_handler(ctx: Context<InitIssuerRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    registry.authority = ctx.accounts.authority.key();
+    registry.symbols = Vec::new();
+    registry.bump = ctx.bumps.registry;
+    Ok(())
+}
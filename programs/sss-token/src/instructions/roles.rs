@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::constants::*;
-use crate::events::{RolesUpdated, MinterUpdated};
+use crate::error::StablecoinError;
+use crate::events::{RolesUpdated, MinterUpdated, MinterRevoked, MinterStatsSnapshotted, QuotaTransferred};
+use crate::instructions::blacklist::create_pda;
 
 #[derive(Accounts)]
 pub struct UpdateRoles<'info> {
@@ -10,9 +12,11 @@ pub struct UpdateRoles<'info> {
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
         constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -37,9 +41,11 @@ pub struct UpdateMinter<'info> {
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
         constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -58,7 +64,179 @@ pub struct UpdateMinter<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct BatchUpdateMinter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: (minter, writable minter PDA) pairs; `minter_index`
+    // selects a pair
+}
+
+#[derive(Accounts)]
+pub struct BulkSetRoleFlag<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+    // remaining_accounts: writable, existing role PDAs of the holders
+}
+
+/// One entry of `batch_update_minter`, with the same semantics as the
+/// arguments of `update_minter`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MinterUpdate {
+    pub minter_index: u8,
+    pub quota: u64,
+    pub epoch_duration: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeMinter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), minter.key().as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    // A minter that never had quota configured fails here with
+    // `AccountNotInitialized`
+    #[account(
+        mut,
+        close = authority,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: The minter being revoked
+    pub minter: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferQuota<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), from_minter_info.minter.as_ref()],
+        bump = from_minter_info.bump,
+    )]
+    pub from_minter_info: Account<'info, MinterInfo>,
+
+    // Passing the same account twice would let the second write win and mint quota
+    #[account(
+        mut,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), to_minter_info.minter.as_ref()],
+        bump = to_minter_info.bump,
+        constraint = to_minter_info.key() != from_minter_info.key() @ StablecoinError::InvalidRoleConfig,
+    )]
+    pub to_minter_info: Account<'info, MinterInfo>,
+}
+
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct SnapshotMinterStats<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+        constraint = !stablecoin.config_frozen() @ StablecoinError::ConfigFrozenDuringTransfer,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), minter_info.minter.as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    // `init` rather than `init_if_needed`: a closed period cannot be rewritten
+    #[account(
+        init,
+        payer = authority,
+        space = MinterStatsSnapshot::LEN,
+        seeds = [
+            MINTER_STATS_SEED,
+            stablecoin.key().as_ref(),
+            minter_info.minter.as_ref(),
+            &period.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub snapshot: Account<'info, MinterStatsSnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetEffectiveRoles<'info> {
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), role.holder.as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+}
+
 pub fn update_roles_handler(ctx: Context<UpdateRoles>, roles: RoleFlags) -> Result<()> {
+    let max_roles = ctx.accounts.stablecoin.max_roles_per_holder;
+    require!(
+        max_roles == 0 || roles.count() <= max_roles,
+        StablecoinError::TooManyRoles
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+
     let role = &mut ctx.accounts.role;
     role.stablecoin = ctx.accounts.stablecoin.key();
     role.holder = ctx.accounts.holder.key();
@@ -73,28 +251,295 @@ pub fn update_roles_handler(ctx: Context<UpdateRoles>, roles: RoleFlags) -> Resu
         is_pauser: roles.is_pauser,
         is_blacklister: roles.is_blacklister,
         is_seizer: roles.is_seizer,
+        is_metadata_updater: roles.is_metadata_updater,
         updated_by: ctx.accounts.authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
 }
 
-pub fn update_minter_handler(ctx: Context<UpdateMinter>, quota: u64) -> Result<()> {
+pub fn update_minter_handler(
+    ctx: Context<UpdateMinter>,
+    quota: u64,
+    epoch_duration: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+
     let minter_info = &mut ctx.accounts.minter_info;
     minter_info.stablecoin = ctx.accounts.stablecoin.key();
     minter_info.minter = ctx.accounts.minter.key();
-    minter_info.quota = quota;
-    // Preserve existing minted_amount (don't reset on quota update)
+    configure_minter(
+        minter_info,
+        quota,
+        epoch_duration,
+        now,
+        ctx.accounts.stablecoin.epoch_alignment,
+    )?;
     minter_info.bump = ctx.bumps.minter_info;
 
     emit!(MinterUpdated {
         stablecoin: ctx.accounts.stablecoin.key(),
         minter: ctx.accounts.minter.key(),
         new_quota: quota,
+        epoch_duration: minter_info.epoch_duration,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Clears the holder's minter flag and closes their `MinterInfo`, refunding
+/// its rent to the authority. Other roles are left as they are.
+pub fn revoke_minter_handler(ctx: Context<RevokeMinter>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+    ctx.accounts.role.roles.is_minter = false;
+
+    emit!(MinterRevoked {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        minter: ctx.accounts.minter.key(),
+        revoked_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    // MinterInfo is closed via Anchor's `close = authority` constraint
+    Ok(())
+}
+
+/// Sets one role (a `RoleFlags` bit) to `value` on every role account passed,
+/// e.g. stripping the minter role from all holders during an incident. The
+/// holders' other roles are untouched.
+pub fn bulk_set_role_flag_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkSetRoleFlag<'info>>,
+    flag: u8,
+    value: bool,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() <= MAX_BATCH_ROLES,
+        StablecoinError::BatchTooLarge
+    );
+
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    let max_roles = ctx.accounts.stablecoin.max_roles_per_holder;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+
+    for role_account in ctx.remaining_accounts.iter() {
+        require!(role_account.is_writable, StablecoinError::BatchAccountMismatch);
+        let mut role = Account::<RoleAccount>::try_from(role_account)?.into_inner();
+        let expected = Pubkey::create_program_address(
+            &[ROLE_SEED, stablecoin_key.as_ref(), role.holder.as_ref(), &[role.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| StablecoinError::BatchAccountMismatch)?;
+        require!(role_account.key() == expected, StablecoinError::BatchAccountMismatch);
+
+        role.roles.set_bit(flag, value)?;
+        require!(
+            max_roles == 0 || role.roles.count() <= max_roles,
+            StablecoinError::TooManyRoles
+        );
+        role.try_serialize(&mut &mut role_account.try_borrow_mut_data()?[..])?;
+
+        emit!(RolesUpdated {
+            stablecoin: stablecoin_key,
+            holder: role.holder,
+            is_minter: role.roles.is_minter,
+            is_burner: role.roles.is_burner,
+            is_pauser: role.roles.is_pauser,
+            is_blacklister: role.roles.is_blacklister,
+            is_seizer: role.roles.is_seizer,
+            is_metadata_updater: role.roles.is_metadata_updater,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Applies `update_minter` to several minters at once, creating any minter
+/// PDA that does not exist yet.
+pub fn batch_update_minter_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchUpdateMinter<'info>>,
+    updates: Vec<MinterUpdate>,
+) -> Result<()> {
+    require!(updates.len() <= MAX_BATCH_MINTER, StablecoinError::BatchTooLarge);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+    let stablecoin_key = ctx.accounts.stablecoin.key();
+    let authority = &ctx.accounts.authority;
+    let epoch_alignment = ctx.accounts.stablecoin.epoch_alignment;
+    let rent = Rent::get()?.minimum_balance(MinterInfo::LEN);
+
+    for update in updates.iter() {
+        let index = update.minter_index as usize * 2;
+        require!(
+            index + 1 < ctx.remaining_accounts.len(),
+            StablecoinError::BatchAccountMismatch
+        );
+        let minter = ctx.remaining_accounts[index].key();
+        let minter_info_account = &ctx.remaining_accounts[index + 1];
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[MINTER_SEED, stablecoin_key.as_ref(), minter.as_ref()],
+            ctx.program_id,
+        );
+        require!(minter_info_account.key() == expected, StablecoinError::BatchAccountMismatch);
+
+        let mut minter_info = if minter_info_account.owner == ctx.program_id
+            && !minter_info_account.data_is_empty()
+        {
+            Account::<MinterInfo>::try_from(minter_info_account)?.into_inner()
+        } else {
+            let signer_seeds: &[&[u8]] = &[MINTER_SEED, stablecoin_key.as_ref(), minter.as_ref(), &[bump]];
+            create_pda(
+                &ctx.accounts.system_program,
+                authority,
+                minter_info_account,
+                MinterInfo::LEN,
+                rent,
+                ctx.program_id,
+                signer_seeds,
+            )?;
+            MinterInfo {
+                stablecoin: stablecoin_key,
+                minter,
+                quota: 0,
+                minted_amount: 0,
+                epoch_duration: 0,
+                epoch_start: 0,
+                minted_this_epoch: 0,
+                bump,
+            }
+        };
+        configure_minter(&mut minter_info, update.quota, update.epoch_duration, now, epoch_alignment)?;
+        minter_info.try_serialize(&mut &mut minter_info_account.try_borrow_mut_data()?[..])?;
+
+        emit!(MinterUpdated {
+            stablecoin: stablecoin_key,
+            minter,
+            new_quota: update.quota,
+            epoch_duration: minter_info.epoch_duration,
+            updated_by: authority.key(),
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Sets the quota and, if given, the epoch duration. The minted totals are
+/// kept; enabling epochs on a lifetime minter starts a fresh epoch.
+fn configure_minter(
+    minter_info: &mut MinterInfo,
+    quota: u64,
+    epoch_duration: Option<i64>,
+    now: i64,
+    epoch_alignment: Option<i64>,
+) -> Result<()> {
+    if let Some(duration) = epoch_duration {
+        require!(
+            duration == 0 || duration >= MIN_EPOCH_DURATION,
+            StablecoinError::InvalidEpochDuration
+        );
+    }
+
+    minter_info.quota = quota;
+    if let Some(duration) = epoch_duration {
+        let enabling = duration > 0 && minter_info.epoch_duration == 0;
+        minter_info.epoch_duration = duration;
+        if enabling {
+            minter_info.epoch_start = minter_info.epoch_start_at(now, epoch_alignment);
+            minter_info.minted_this_epoch = 0;
+        }
+    }
+    Ok(())
+}
+
+pub fn transfer_quota_handler(ctx: Context<TransferQuota>, amount: u64) -> Result<()> {
+    require!(amount > 0, StablecoinError::ZeroAmount);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+
+    // Only unused headroom can move — the source must still cover what it already minted
+    let from = &mut ctx.accounts.from_minter_info;
+    let available = from.quota.saturating_sub(from.quota_used());
+    require!(amount <= available, StablecoinError::QuotaExceeded);
+    from.quota -= amount;
+
+    let to = &mut ctx.accounts.to_minter_info;
+    to.quota = to
+        .quota
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit!(QuotaTransferred {
+        stablecoin: ctx.accounts.stablecoin.key(),
+        from_minter: ctx.accounts.from_minter_info.minter,
+        to_minter: ctx.accounts.to_minter_info.minter,
+        amount,
+        from_quota: ctx.accounts.from_minter_info.quota,
+        to_quota: ctx.accounts.to_minter_info.quota,
         updated_by: ctx.accounts.authority.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
 }
+
+/// Records the minter's counters for `period`. With `reset`, the live
+/// `minted_amount` starts over at zero; the lifetime total stays recoverable
+/// by summing the period snapshots. Only epoch minters can be reset: without
+/// epochs `minted_amount` is what the quota is checked against, and zeroing
+/// it would hand the minter a fresh quota.
+pub fn snapshot_minter_stats_handler(
+    ctx: Context<SnapshotMinterStats>,
+    period: u64,
+    reset: bool,
+) -> Result<()> {
+    require!(
+        !reset || ctx.accounts.minter_info.epoch_duration > 0,
+        StablecoinError::ResetWouldFreeQuota
+    );
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_authority_action(now);
+    let minter_info = &mut ctx.accounts.minter_info;
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.stablecoin = ctx.accounts.stablecoin.key();
+    snapshot.minter = minter_info.minter;
+    snapshot.period = period;
+    snapshot.quota = minter_info.quota;
+    snapshot.minted_amount = minter_info.minted_amount;
+    snapshot.minted_this_epoch = minter_info.minted_this_epoch;
+    snapshot.reset = reset;
+    snapshot.taken_at = now;
+    snapshot.taken_by = ctx.accounts.authority.key();
+    snapshot.bump = ctx.bumps.snapshot;
+
+    if reset {
+        minter_info.minted_amount = 0;
+    }
+
+    emit!(MinterStatsSnapshotted {
+        stablecoin: snapshot.stablecoin,
+        minter: snapshot.minter,
+        period,
+        minted_amount: snapshot.minted_amount,
+        reset,
+        taken_by: snapshot.taken_by,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Read-only: returns the holder's effective roles as a `RoleFlags` bitfield.
+pub fn get_effective_roles_handler(ctx: Context<GetEffectiveRoles>) -> Result<u8> {
+    Ok(ctx.accounts.role.effective_roles().to_bits())
+}
@@ -1,8 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke_signed;
 use spl_token_2022::{
     extension::StateWithExtensions,
-    instruction as token_instruction,
+    onchain,
     state::Account as SplAccount,
 };
 
@@ -16,8 +15,10 @@ pub struct Seize<'info> {
     pub seizer: Signer<'info>,
 
     #[account(
-        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
         bump = stablecoin.bump,
+        constraint = stablecoin.mint == mint.key() @ StablecoinError::MintMismatch,
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
@@ -34,6 +35,11 @@ pub struct Seize<'info> {
     #[account(mut)]
     pub source_token_account: AccountInfo<'info>,
 
+    /// CHECK: Blacklist PDA of the source account's owner; it may be
+    /// uninitialized. The owner is only known once the token account is
+    /// unpacked, so the address is derived and checked in the handler.
+    pub source_blacklist_entry: UncheckedAccount<'info>,
+
     /// CHECK: Destination treasury token account
     #[account(mut)]
     pub destination_token_account: AccountInfo<'info>,
@@ -43,61 +49,127 @@ pub struct Seize<'info> {
     pub token_program: AccountInfo<'info>,
 }
 
-pub fn handler(ctx: Context<Seize>) -> Result<()> {
+/// Moves `amount` out of the source account, or its whole balance when
+/// `None`, and returns the amount seized (fee included), so a program
+/// composing seizures via CPI can read it back with `get_return_data`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Seize<'info>>,
+    amount: Option<u64>,
+) -> Result<u64> {
     // Feature gate: only SSS-2 tokens support seizure
     require!(
         ctx.accounts.stablecoin.enable_permanent_delegate,
         StablecoinError::ComplianceNotEnabled
     );
     require!(ctx.accounts.role.roles.is_seizer, StablecoinError::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.stablecoin.record_activity_by(ctx.accounts.seizer.key(), now);
+
+    // Only deserialize accounts the token program actually owns — an arbitrary
+    // account could otherwise be unpacked as if it held a token balance.
+    require!(
+        *ctx.accounts.source_token_account.owner == ctx.accounts.token_program.key(),
+        StablecoinError::InvalidTokenAccount
+    );
 
-    // Read full balance from source token account.
+    // Read the balance from the source token account.
     // Must use StateWithExtensions (not Pack::unpack) because Token-2022 accounts
     // carry TLV extension data beyond the base 165-byte layout, and Pack::unpack
     // enforces a strict length == 165 check that always fails on Token-2022 accounts.
     let source_data = ctx.accounts.source_token_account.try_borrow_data()?;
     let source_account = StateWithExtensions::<SplAccount>::unpack(&source_data)?;
-    let amount = source_account.base.amount;
+    let balance = source_account.base.amount;
+    let source_owner = source_account.base.owner;
     drop(source_data);
 
+    // A temporary blacklisting that has lapsed no longer backs a seizure; the
+    // transfer hook applies the same rule
+    let (entry_key, _) = Pubkey::find_program_address(
+        &[BLACKLIST_SEED, ctx.accounts.stablecoin.key().as_ref(), source_owner.as_ref()],
+        ctx.program_id,
+    );
+    let entry_info = ctx.accounts.source_blacklist_entry.to_account_info();
+    require!(entry_info.key() == entry_key, StablecoinError::BlacklistEntryMismatch);
+    if entry_info.owner == ctx.program_id && !entry_info.data_is_empty() {
+        let entry = BlacklistEntry::decode_any_version(&entry_info.try_borrow_data()?)?;
+        require!(
+            entry.blacklist_active(now),
+            StablecoinError::BlacklistExpired
+        );
+    }
+
+    // A court order may cover only part of the balance; the rest stays put
+    let amount = amount.unwrap_or(balance);
+    require!(amount <= balance, StablecoinError::SeizeAmountExceedsBalance);
+
     require!(amount > 0, StablecoinError::ZeroAmount);
+    let max_seize_per_tx = ctx.accounts.stablecoin.max_seize_per_tx;
+    require!(
+        max_seize_per_tx == 0 || amount <= max_seize_per_tx,
+        StablecoinError::SeizeAmountTooLarge
+    );
 
-    // CPI: transfer_checked using permanent delegate authority (stablecoin PDA)
-    let mint_key = ctx.accounts.mint.key();
-    let signer_seeds: &[&[u8]] = &[
-        STABLECOIN_SEED,
-        mint_key.as_ref(),
-        &[ctx.accounts.stablecoin.bump],
-    ];
+    // Enforcement fee, if configured, goes to the fee treasury passed as the
+    // first remaining account; the rest goes to the destination.
+    let stablecoin = &ctx.accounts.stablecoin;
+    let fee = apply_bps(amount, stablecoin.seize_fee_bps)?;
+    if fee > 0 {
+        let fee_treasury = ctx
+            .remaining_accounts
+            .first()
+            .filter(|info| info.key() == stablecoin.seize_fee_treasury)
+            .ok_or(StablecoinError::FeeTreasuryMissing)?;
+        transfer_as_delegate(&ctx, fee_treasury, fee)?;
+    }
+    let destination = ctx.accounts.destination_token_account.to_account_info();
+    transfer_as_delegate(&ctx, &destination, amount - fee)?;
 
-    invoke_signed(
-        &token_instruction::transfer_checked(
-            &ctx.accounts.token_program.key(),
-            &ctx.accounts.source_token_account.key(),
-            &ctx.accounts.mint.key(),
-            &ctx.accounts.destination_token_account.key(),
-            &ctx.accounts.stablecoin.key(), // permanent delegate
-            &[],
-            amount,
-            ctx.accounts.stablecoin.decimals,
-        )?,
-        &[
-            ctx.accounts.source_token_account.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.destination_token_account.to_account_info(),
-            ctx.accounts.stablecoin.to_account_info(),
-        ],
-        &[signer_seeds],
-    )?;
+    let stablecoin = &mut ctx.accounts.stablecoin;
+    stablecoin.total_seized = stablecoin
+        .total_seized
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    stablecoin.seizure_count += 1;
 
     emit!(TokensSeized {
         stablecoin: ctx.accounts.stablecoin.key(),
         from: ctx.accounts.source_token_account.key(),
         to: ctx.accounts.destination_token_account.key(),
         amount,
+        fee,
         seized_by: ctx.accounts.seizer.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
+    Ok(amount)
+}
+
+/// transfer_checked out of the source account, signed by the stablecoin PDA
+/// as permanent delegate.
+fn transfer_as_delegate<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, Seize<'info>>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[u8]] = &[
+        STABLECOIN_SEED,
+        mint_key.as_ref(),
+        &[ctx.accounts.stablecoin.bump],
+    ];
+
+    // Remaining accounts double as the transfer hook's accounts (hook program,
+    // ExtraAccountMetaList and the extras it resolves); the helper picks them out
+    onchain::invoke_transfer_checked(
+        &ctx.accounts.token_program.key(),
+        ctx.accounts.source_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        destination.clone(),
+        ctx.accounts.stablecoin.to_account_info(), // permanent delegate
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.stablecoin.decimals,
+        &[signer_seeds],
+    )?;
     Ok(())
 }
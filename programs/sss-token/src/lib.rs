@@ -7,7 +7,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
-use state::RoleFlags;
+use state::{BlacklistReasonCode, HookMode, RoleFlags};
 
 declare_id!("2D8s3bH6vD3LG7wqzvpSvYFysYoSK4wwggHCptaKFJJQ");
 
@@ -17,18 +17,29 @@ pub mod sss_token {
 
     // === Core Instructions (all presets) ===
 
-    pub fn initialize(ctx: Context<InitializeStablecoin>, params: InitializeParams) -> Result<()> {
+    pub fn initialize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeStablecoin<'info>>,
+        params: InitializeParams,
+    ) -> Result<()> {
         instructions::initialize::handler(ctx, params)
     }
 
-    pub fn mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
-        instructions::mint::handler(ctx, amount)
+    pub fn mint_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintTokens<'info>>,
+        amount: u64,
+        reference: Option<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::mint::handler(ctx, amount, reference)
     }
 
     pub fn burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
         instructions::burn::handler(ctx, amount)
     }
 
+    pub fn burn_from_vault(ctx: Context<BurnFromVault>, amount: u64) -> Result<()> {
+        instructions::burn::vault_handler(ctx, amount)
+    }
+
     pub fn freeze_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
         instructions::freeze::freeze_handler(ctx)
     }
@@ -49,25 +60,290 @@ pub mod sss_token {
         instructions::roles::update_roles_handler(ctx, roles)
     }
 
-    pub fn update_minter(ctx: Context<UpdateMinter>, quota: u64) -> Result<()> {
-        instructions::roles::update_minter_handler(ctx, quota)
+    pub fn update_minter(
+        ctx: Context<UpdateMinter>,
+        quota: u64,
+        epoch_duration: Option<i64>,
+    ) -> Result<()> {
+        instructions::roles::update_minter_handler(ctx, quota, epoch_duration)
+    }
+
+    pub fn batch_update_minter<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchUpdateMinter<'info>>,
+        updates: Vec<MinterUpdate>,
+    ) -> Result<()> {
+        instructions::roles::batch_update_minter_handler(ctx, updates)
+    }
+
+    pub fn bulk_set_role_flag<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkSetRoleFlag<'info>>,
+        flag: u8,
+        value: bool,
+    ) -> Result<()> {
+        instructions::roles::bulk_set_role_flag_handler(ctx, flag, value)
+    }
+
+    pub fn revoke_minter(ctx: Context<RevokeMinter>) -> Result<()> {
+        instructions::roles::revoke_minter_handler(ctx)
+    }
+
+    pub fn transfer_quota(ctx: Context<TransferQuota>, amount: u64) -> Result<()> {
+        instructions::roles::transfer_quota_handler(ctx, amount)
+    }
+
+    pub fn snapshot_minter_stats(
+        ctx: Context<SnapshotMinterStats>,
+        period: u64,
+        reset: bool,
+    ) -> Result<()> {
+        instructions::roles::snapshot_minter_stats_handler(ctx, period, reset)
+    }
+
+    pub fn get_effective_roles(ctx: Context<GetEffectiveRoles>) -> Result<u8> {
+        instructions::roles::get_effective_roles_handler(ctx)
     }
 
     pub fn transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
-        instructions::authority::handler(ctx)
+        instructions::authority::transfer_authority_handler(ctx)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::authority::accept_authority_handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::authority::cancel_authority_transfer_handler(ctx)
+    }
+
+    pub fn claim_recovery(ctx: Context<ClaimRecovery>) -> Result<()> {
+        instructions::authority::claim_recovery_handler(ctx)
+    }
+
+    // === Configuration ===
+
+    pub fn set_mint_introspection(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        instructions::config::set_mint_introspection_handler(ctx, enabled)
+    }
+
+    pub fn set_burn_address(ctx: Context<UpdateConfig>, burn_address: Pubkey) -> Result<()> {
+        instructions::config::set_burn_address_handler(ctx, burn_address)
+    }
+
+    pub fn set_event_mask(ctx: Context<UpdateConfig>, event_mask: u8) -> Result<()> {
+        instructions::config::set_event_mask_handler(ctx, event_mask)
+    }
+
+    pub fn set_max_single_mint(ctx: Context<UpdateConfig>, max_single_mint: u64) -> Result<()> {
+        instructions::config::set_max_single_mint_handler(ctx, max_single_mint)
+    }
+
+    pub fn set_allow_burn_when_paused(ctx: Context<UpdateConfig>, allowed: bool) -> Result<()> {
+        instructions::config::set_allow_burn_when_paused_handler(ctx, allowed)
+    }
+
+    pub fn set_authority_transfer_cooldown(ctx: Context<UpdateConfig>, cooldown: i64) -> Result<()> {
+        instructions::config::set_authority_transfer_cooldown_handler(ctx, cooldown)
+    }
+
+    pub fn set_hook_mode(ctx: Context<UpdateConfig>, hook_mode: HookMode) -> Result<()> {
+        instructions::config::set_hook_mode_handler(ctx, hook_mode)
+    }
+
+    pub fn set_require_rent_exempt_destination(ctx: Context<UpdateConfig>, required: bool) -> Result<()> {
+        instructions::config::set_require_rent_exempt_destination_handler(ctx, required)
+    }
+
+    pub fn set_seize_fee(ctx: Context<UpdateConfig>, fee_bps: u16, treasury: Pubkey) -> Result<()> {
+        instructions::config::set_seize_fee_handler(ctx, fee_bps, treasury)
+    }
+
+    pub fn set_max_seize_per_tx(ctx: Context<UpdateConfig>, max_seize_per_tx: u64) -> Result<()> {
+        instructions::config::set_max_seize_per_tx_handler(ctx, max_seize_per_tx)
+    }
+
+    pub fn set_max_transfer_per_tx(ctx: Context<UpdateConfig>, max_transfer_per_tx: u64) -> Result<()> {
+        instructions::config::set_max_transfer_per_tx_handler(ctx, max_transfer_per_tx)
+    }
+
+    pub fn set_min_hold_balance(ctx: Context<UpdateConfig>, min_hold_balance: u64) -> Result<()> {
+        instructions::config::set_min_hold_balance_handler(ctx, min_hold_balance)
+    }
+
+    pub fn set_display_decimals(ctx: Context<UpdateConfig>, display_decimals: u8) -> Result<()> {
+        instructions::config::set_display_decimals_handler(ctx, display_decimals)
+    }
+
+    pub fn set_epoch_alignment(ctx: Context<UpdateConfig>, offset: Option<i64>) -> Result<()> {
+        instructions::config::set_epoch_alignment_handler(ctx, offset)
+    }
+
+    pub fn set_max_frozen_alert(ctx: Context<UpdateConfig>, threshold: u64) -> Result<()> {
+        instructions::config::set_max_frozen_alert_handler(ctx, threshold)
+    }
+
+    pub fn set_freeze_config_during_transfer(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        instructions::config::set_freeze_config_during_transfer_handler(ctx, enabled)
+    }
+
+    pub fn set_max_roles_per_holder(ctx: Context<UpdateConfig>, max_roles: u8) -> Result<()> {
+        instructions::config::set_max_roles_per_holder_handler(ctx, max_roles)
+    }
+
+    pub fn set_mint_lock_duration(ctx: Context<UpdateConfig>, duration: i64) -> Result<()> {
+        instructions::config::set_mint_lock_duration_handler(ctx, duration)
+    }
+
+    pub fn set_reserve_oracle(
+        ctx: Context<UpdateConfig>,
+        oracle: Option<Pubkey>,
+        min_reserve_ratio_bps: u16,
+    ) -> Result<()> {
+        instructions::config::set_reserve_oracle_handler(ctx, oracle, min_reserve_ratio_bps)
+    }
+
+    pub fn set_recovery_authority(
+        ctx: Context<UpdateConfig>,
+        recovery_authority: Option<Pubkey>,
+        inactivity_period: i64,
+    ) -> Result<()> {
+        instructions::config::set_recovery_authority_handler(ctx, recovery_authority, inactivity_period)
+    }
+
+    pub fn authority_heartbeat(ctx: Context<UpdateConfig>) -> Result<()> {
+        instructions::config::authority_heartbeat_handler(ctx)
+    }
+
+    pub fn add_freeze_protected(ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+        instructions::config::add_freeze_protected_handler(ctx, account)
+    }
+
+    pub fn remove_freeze_protected(ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+        instructions::config::remove_freeze_protected_handler(ctx, account)
+    }
+
+    pub fn add_denied_integration(ctx: Context<UpdateConfig>, owner: Pubkey) -> Result<()> {
+        instructions::config::add_denied_integration_handler(ctx, owner)
+    }
+
+    pub fn remove_denied_integration(ctx: Context<UpdateConfig>, owner: Pubkey) -> Result<()> {
+        instructions::config::remove_denied_integration_handler(ctx, owner)
+    }
+
+    pub fn set_restrict_redemptions(ctx: Context<UpdateConfig>, restrict: bool) -> Result<()> {
+        instructions::config::set_restrict_redemptions_handler(ctx, restrict)
+    }
+
+    pub fn add_redemption_account(ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+        instructions::config::add_redemption_account_handler(ctx, account)
+    }
+
+    pub fn remove_redemption_account(ctx: Context<UpdateConfig>, account: Pubkey) -> Result<()> {
+        instructions::config::remove_redemption_account_handler(ctx, account)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, name: String, uri: String) -> Result<()> {
+        instructions::config::update_metadata_handler(ctx, name, uri)
+    }
+
+    // === Maintenance ===
+
+    pub fn repair_bump<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RepairBump<'info>>,
+    ) -> Result<u8> {
+        instructions::maintenance::repair_bump_handler(ctx)
+    }
+
+    pub fn migrate_stablecoin(ctx: Context<MigrateStablecoin>) -> Result<()> {
+        instructions::maintenance::migrate_stablecoin_handler(ctx)
+    }
+
+    pub fn migrate_role_account(ctx: Context<MigrateRoleAccount>) -> Result<()> {
+        instructions::maintenance::migrate_role_account_handler(ctx)
+    }
+
+    pub fn migrate_minter_info(ctx: Context<MigrateMinterInfo>) -> Result<()> {
+        instructions::maintenance::migrate_minter_info_handler(ctx)
+    }
+
+    pub fn migrate_blacklist_entry(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+        instructions::maintenance::migrate_blacklist_entry_handler(ctx)
+    }
+
+    pub fn check_authorities(ctx: Context<CheckAuthorities>) -> Result<u8> {
+        instructions::maintenance::check_authorities_handler(ctx)
+    }
+
+    pub fn get_circulating_supply(ctx: Context<GetCirculatingSupply>) -> Result<u64> {
+        instructions::maintenance::get_circulating_supply_handler(ctx)
+    }
+
+    pub fn emit_config_snapshot(ctx: Context<EmitConfigSnapshot>) -> Result<()> {
+        instructions::maintenance::emit_config_snapshot_handler(ctx)
+    }
+
+    pub fn close_stablecoin(ctx: Context<CloseStablecoin>) -> Result<()> {
+        instructions::maintenance::close_stablecoin_handler(ctx)
+    }
+
+    pub fn init_issuer_registry(ctx: Context<InitIssuerRegistry>) -> Result<()> {
+        instructions::registry::init_issuer_registry_handler(ctx)
     }
 
     // === SSS-2 Compliance Instructions ===
 
-    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, reason: String) -> Result<()> {
-        instructions::blacklist::add_handler(ctx, reason)
+    pub fn add_to_blacklist(
+        ctx: Context<AddToBlacklist>,
+        reason: String,
+        reason_code: BlacklistReasonCode,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::blacklist::add_handler(ctx, reason, reason_code, expires_at)
+    }
+
+    pub fn import_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ImportBlacklist<'info>>,
+        addresses: Vec<Pubkey>,
+        reason: String,
+        reason_code: BlacklistReasonCode,
+    ) -> Result<ImportBlacklistResult> {
+        instructions::blacklist::import_handler(ctx, addresses, reason, reason_code)
     }
 
     pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
         instructions::blacklist::remove_handler(ctx)
     }
 
-    pub fn seize(ctx: Context<Seize>) -> Result<()> {
-        instructions::seize::handler(ctx)
+    pub fn try_remove_from_blacklist(ctx: Context<TryRemoveFromBlacklist>) -> Result<bool> {
+        instructions::blacklist::try_remove_handler(ctx)
+    }
+
+    pub fn reassign_blacklist_owner(
+        ctx: Context<ReassignBlacklistOwner>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::blacklist::reassign_owner_handler(ctx, new_owner)
+    }
+
+    pub fn lockdown(
+        ctx: Context<Lockdown>,
+        reason: String,
+        reason_code: BlacklistReasonCode,
+    ) -> Result<()> {
+        instructions::blacklist::lockdown_handler(ctx, reason, reason_code)
+    }
+
+    pub fn get_compliance_stats(ctx: Context<GetComplianceStats>) -> Result<ComplianceStats> {
+        instructions::blacklist::get_compliance_stats_handler(ctx)
+    }
+
+    pub fn seize<'info>(ctx: Context<'_, '_, 'info, 'info, Seize<'info>>) -> Result<u64> {
+        instructions::seize::handler(ctx, None)
+    }
+
+    pub fn seize_amount<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Seize<'info>>,
+        amount: u64,
+    ) -> Result<u64> {
+        instructions::seize::handler(ctx, Some(amount))
     }
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 use crate::constants::*;
+use crate::error::StablecoinError;
 
 /// Main stablecoin configuration PDA.
 /// Seeds: [b"stablecoin", mint.key().as_ref()]
@@ -23,6 +25,90 @@ pub struct StablecoinState {
     pub paused: bool,
     pub total_minted: u64,
     pub total_burned: u64,
+    /// Optional guards (authority-configurable)
+    pub mint_introspection: bool,
+    /// Freeze authority is the ["freeze_auth", mint] PDA instead of this PDA
+    pub separate_freeze_authority: bool,
+    pub freeze_authority_bump: u8,
+    /// Wallet whose token accounts the hook treats as a burn sink; transfers
+    /// to it skip destination checks (default pubkey = disabled)
+    pub burn_address: Pubkey,
+    /// Suppressed event categories (EVENT_* bits; 0 = emit everything)
+    pub event_mask: u8,
+    /// Ceiling on a single mint_tokens amount (0 = no ceiling)
+    pub max_single_mint: u64,
+    /// Two-step authority transfer: proposed authority and when it was proposed
+    pub pending_authority: Option<Pubkey>,
+    pub pending_since: i64,
+    /// Seconds the proposed authority must wait before accepting
+    pub authority_transfer_cooldown: i64,
+    /// Whether the transfer hook blocks blacklisted transfers or only logs them
+    pub hook_mode: HookMode,
+    /// Token accounts freeze_account refuses to freeze (treasury, protocol accounts)
+    pub freeze_protected: Vec<Pubkey>,
+    /// Longest `uri` this account was sized for (MAX_URI_LEN unless overridden at init)
+    pub max_uri_len: u16,
+    /// Share of each seizure (bps) routed to `seize_fee_treasury`
+    pub seize_fee_bps: u16,
+    pub seize_fee_treasury: Pubkey,
+    /// Most roles update_roles may grant one holder (0 = no limit). The
+    /// authority's all-roles grant at init is exempt.
+    pub max_roles_per_holder: u8,
+    /// When set, burns are only accepted from `redemption_accounts`
+    pub restrict_redemptions: bool,
+    /// Token accounts burns may be initiated from while restricted
+    pub redemption_accounts: Vec<Pubkey>,
+    /// Seconds a token account that received a mint cannot transfer out
+    /// (0 = disabled). Tracked per account in `MintLock`.
+    pub mint_lock_duration: i64,
+    /// Compliance counters reported by get_compliance_stats. Entries created
+    /// before the counter existed are not included.
+    pub blacklist_count: u64,
+    pub total_seized: u64,
+    pub seizure_count: u64,
+    /// Account publishing the declared reserves (u64 LE at offset 0, in base
+    /// units); when set, mints may not push supply past the reserve ratio
+    pub reserve_oracle: Option<Pubkey>,
+    /// Minimum reserves / supply, in bps (10_000 = fully backed)
+    pub min_reserve_ratio_bps: u16,
+    /// Backup key that may claim the authority role once the authority has
+    /// been inactive for `recovery_inactivity_period` seconds
+    pub recovery_authority: Option<Pubkey>,
+    pub recovery_inactivity_period: i64,
+    /// Last instruction signed by the authority (see `record_authority_action`)
+    pub last_authority_action: i64,
+    /// When set, the transfer hook rejects holder transfers to token accounts
+    /// that are not rent-exempt. Seizures are exempt.
+    pub require_rent_exempt_destination: bool,
+    /// Ceiling on the amount a single seize may move, fee included (0 = no ceiling)
+    pub max_seize_per_tx: u64,
+    /// Keep burns (redemptions) open while the stablecoin is paused
+    pub allow_burn_when_paused: bool,
+    /// Ceiling on a single holder transfer, enforced by the transfer hook
+    /// (0 = no ceiling). Seizures are exempt.
+    pub max_transfer_per_tx: u64,
+    /// Smallest nonzero balance a holder transfer may leave behind in the
+    /// source account, enforced by the transfer hook (0 = off)
+    pub min_hold_balance: u64,
+    /// Preferred number of decimals when rendering amounts (<= `decimals`);
+    /// display guidance for indexers, not enforced on-chain
+    pub display_decimals: u8,
+    /// Protocol programs, or individual vault authorities, whose token
+    /// accounts may not receive holder transfers; enforced by the transfer hook
+    pub denied_integrations: Vec<Pubkey>,
+    /// When set, minter epochs start on `offset + k * epoch_duration`
+    /// boundaries (Unix seconds) instead of whenever they roll, e.g.
+    /// `Some(0)` with daily epochs resets quotas at 00:00 UTC
+    pub epoch_alignment: Option<i64>,
+    /// Accounts frozen through `freeze_account` / `lockdown` minus those
+    /// thawed (floored at 0)
+    pub frozen_count: u64,
+    /// `MassFreezeAlert` fires when `frozen_count` rises above this (0 = off)
+    pub max_frozen_alert: u64,
+    /// Reject authority-gated changes (config, roles, minters, maintenance)
+    /// while `pending_authority` is set, so an outgoing authority cannot
+    /// reconfigure the token during the handover
+    pub freeze_config_during_transfer: bool,
     /// PDA bump
     pub bump: u8,
 }
@@ -41,11 +127,258 @@ impl StablecoinState {
         + 1                     // paused
         + 8                     // total_minted
         + 8                     // total_burned
+        + 1                     // mint_introspection
+        + 1                     // separate_freeze_authority
+        + 1                     // freeze_authority_bump
+        + 32                    // burn_address
+        + 1                     // event_mask
+        + 8                     // max_single_mint
+        + (1 + 32)              // pending_authority
+        + 8                     // pending_since
+        + 8                     // authority_transfer_cooldown
+        + 1                     // hook_mode
+        + (4 + 32 * MAX_FREEZE_PROTECTED) // freeze_protected
+        + 2                     // max_uri_len
+        + 2                     // seize_fee_bps
+        + 32                    // seize_fee_treasury
+        + 1                     // max_roles_per_holder
+        + 1                     // restrict_redemptions
+        + (4 + 32 * MAX_REDEMPTION_ACCOUNTS) // redemption_accounts
+        + 8                     // mint_lock_duration
+        + 8                     // blacklist_count
+        + 8                     // total_seized
+        + 8                     // seizure_count
+        + (1 + 32)              // reserve_oracle
+        + 2                     // min_reserve_ratio_bps
+        + (1 + 32)              // recovery_authority
+        + 8                     // recovery_inactivity_period
+        + 8                     // last_authority_action
+        + 1                     // require_rent_exempt_destination
+        + 8                     // max_seize_per_tx
+        + 1                     // allow_burn_when_paused
+        + 8                     // max_transfer_per_tx
+        + 8                     // min_hold_balance
+        + 1                     // display_decimals
+        + (4 + 32 * MAX_DENIED_INTEGRATIONS) // denied_integrations
+        + (1 + 8)               // epoch_alignment
+        + 8                     // frozen_count
+        + 8                     // max_frozen_alert
+        + 1                     // freeze_config_during_transfer
         + 1;                    // bump
 
+    /// Size of stablecoin accounts created by the original release, where
+    /// `bump` directly followed `total_burned`. Every later field was
+    /// inserted before `bump`, so these accounts no longer deserialize.
+    pub const LEGACY_LEN: usize = 8 // discriminator
+        + 32 + 32                   // authority, mint
+        + (4 + MAX_NAME_LEN) + (4 + MAX_SYMBOL_LEN) + (4 + MAX_URI_LEN)
+        + 1 + 4                     // decimals, feature flags and paused
+        + 8 + 8                     // total_minted, total_burned
+        + 1;                        // bump
+
+    /// Decodes a stablecoin account in the current layout or the original
+    /// one. Settings the original layout lacked take the values `initialize`
+    /// writes for a new stablecoin.
+    pub fn decode_any_version(data: &[u8]) -> Result<Self> {
+        if data.len() != Self::LEGACY_LEN {
+            return Self::try_deserialize(&mut &data[..]);
+        }
+        require!(data[..8] == Self::DISCRIMINATOR, ErrorCode::AccountDidNotDeserialize);
+        let legacy = LegacyStablecoinState::deserialize(&mut &data[8..])?;
+
+        Ok(Self {
+            authority: legacy.authority,
+            mint: legacy.mint,
+            name: legacy.name,
+            symbol: legacy.symbol,
+            uri: legacy.uri,
+            decimals: legacy.decimals,
+            enable_permanent_delegate: legacy.enable_permanent_delegate,
+            enable_transfer_hook: legacy.enable_transfer_hook,
+            default_account_frozen: legacy.default_account_frozen,
+            paused: legacy.paused,
+            total_minted: legacy.total_minted,
+            total_burned: legacy.total_burned,
+            mint_introspection: false,
+            separate_freeze_authority: false,
+            freeze_authority_bump: legacy.bump,
+            burn_address: Pubkey::default(),
+            event_mask: 0,
+            max_single_mint: 0,
+            pending_authority: None,
+            pending_since: 0,
+            authority_transfer_cooldown: 0,
+            hook_mode: HookMode::Enforce,
+            freeze_protected: Vec::new(),
+            max_uri_len: MAX_URI_LEN as u16,
+            seize_fee_bps: 0,
+            seize_fee_treasury: Pubkey::default(),
+            max_roles_per_holder: 0,
+            restrict_redemptions: false,
+            redemption_accounts: Vec::new(),
+            mint_lock_duration: 0,
+            blacklist_count: 0,
+            total_seized: 0,
+            seizure_count: 0,
+            reserve_oracle: None,
+            min_reserve_ratio_bps: 0,
+            recovery_authority: None,
+            recovery_inactivity_period: 0,
+            last_authority_action: 0,
+            require_rent_exempt_destination: false,
+            max_seize_per_tx: 0,
+            allow_burn_when_paused: false,
+            max_transfer_per_tx: 0,
+            min_hold_balance: 0,
+            display_decimals: legacy.decimals,
+            denied_integrations: Vec::new(),
+            epoch_alignment: None,
+            frozen_count: 0,
+            max_frozen_alert: 0,
+            freeze_config_during_transfer: false,
+            bump: legacy.bump,
+        })
+    }
+
+    /// Account size when the `uri` is allowed up to `max_uri_len` bytes
+    /// instead of the default `MAX_URI_LEN`.
+    pub fn space(max_uri_len: usize) -> usize {
+        Self::LEN - MAX_URI_LEN + max_uri_len
+    }
+
     pub fn is_sss2(&self) -> bool {
         self.enable_permanent_delegate && self.enable_transfer_hook
     }
+
+    /// Seed prefix and bump of the PDA that signs freeze/thaw CPIs.
+    pub fn freeze_authority_seeds(&self) -> (&'static [u8], u8) {
+        if self.separate_freeze_authority {
+            (FREEZE_AUTHORITY_SEED, self.freeze_authority_bump)
+        } else {
+            (STABLECOIN_SEED, self.bump)
+        }
+    }
+
+    /// Tokens minted through this program and not yet burned.
+    pub fn circulating_supply(&self) -> Result<u64> {
+        self.total_minted
+            .checked_sub(self.total_burned)
+            .ok_or_else(|| StablecoinError::SupplyAccountingError.into())
+    }
+
+    /// Adds a burn to `total_burned`, refusing to let it pass `total_minted`
+    /// (only possible if supply entered the mint outside this program).
+    pub fn record_burn(&mut self, amount: u64) -> Result<()> {
+        let total_burned = self
+            .total_burned
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        require!(total_burned <= self.total_minted, StablecoinError::SupplyAccountingError);
+        self.total_burned = total_burned;
+        Ok(())
+    }
+
+    /// Whether events in `category` (an EVENT_* bit) should be emitted.
+    pub fn emits(&self, category: u8) -> bool {
+        self.event_mask & category == 0
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether `freeze_config_during_transfer` currently locks the
+    /// authority-gated instructions, i.e. a handover is pending.
+    pub fn config_frozen(&self) -> bool {
+        self.freeze_config_during_transfer && self.pending_authority.is_some()
+    }
+
+    /// Records authority activity for the recovery timer. Authority-gated
+    /// handlers call this directly and role-gated ones through
+    /// `record_activity_by`, so a recovery key can only take over an
+    /// authority that has gone quiet.
+    pub fn record_authority_action(&mut self, now: i64) {
+        self.last_authority_action = now;
+    }
+
+    /// `record_authority_action` for role-gated instructions, which count
+    /// only when the authority itself signs them.
+    pub fn record_activity_by(&mut self, signer: Pubkey, now: i64) {
+        if signer == self.authority {
+            self.record_authority_action(now);
+        }
+    }
+
+    /// Whether a burn may be initiated from `token_account`.
+    pub fn can_redeem_from(&self, token_account: &Pubkey) -> bool {
+        !self.restrict_redemptions || self.redemption_accounts.contains(token_account)
+    }
+
+    /// Single source of truth for pause semantics. Handlers ask whether their
+    /// operation may proceed instead of reading `paused` directly.
+    pub fn is_operation_allowed(&self, op: OperationKind) -> bool {
+        match op {
+            OperationKind::Mint => !self.is_paused(),
+            OperationKind::Burn => !self.is_paused() || self.allow_burn_when_paused,
+        }
+    }
+}
+
+/// `StablecoinState` as written by the original release, read only by
+/// `StablecoinState::decode_any_version`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyStablecoinState {
+    authority: Pubkey,
+    mint: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    enable_permanent_delegate: bool,
+    enable_transfer_hook: bool,
+    default_account_frozen: bool,
+    paused: bool,
+    total_minted: u64,
+    total_burned: u64,
+    bump: u8,
+}
+
+/// `amount * bps / 10_000`, rounded down.
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+    let scaled = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(StablecoinError::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    u64::try_from(scaled).map_err(|_| StablecoinError::MathOverflow.into())
+}
+
+/// How the transfer hook treats blacklist violations.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum HookMode {
+    /// Reject the transfer
+    #[default]
+    Enforce,
+    /// Emit `TransferDenied` from the hook but let the transfer through
+    Monitor,
+}
+
+/// Category of a blacklisting, for aggregating entries in compliance
+/// reports. The free-text `reason` carries the details.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BlacklistReasonCode {
+    /// Also what entries created before reason codes read as
+    #[default]
+    Other,
+    Sanctions,
+    Fraud,
+    CourtOrder,
+}
+
+/// Operations subject to pause checks.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OperationKind {
+    Mint,
+    Burn,
 }
 
 /// Role assignment PDA.
@@ -64,6 +397,38 @@ impl RoleAccount {
         + 32                    // holder
         + RoleFlags::LEN        // roles
         + 1;                    // bump
+
+    /// Size of role accounts created before any flag was added to the
+    /// original five. Accounts between this and `LEN` hold a prefix of the
+    /// current flags.
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 5 + 1;
+
+    /// Decodes a role account written with any earlier `RoleFlags` layout.
+    /// Flags missing from the stored data default to false.
+    pub fn decode_any_version(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= Self::LEGACY_LEN
+                && data.len() <= Self::LEN
+                && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::AccountDidNotDeserialize
+        );
+        let flags_end = data.len() - 1;
+        let mut flags = [0u8; RoleFlags::LEN];
+        flags[..flags_end - 72].copy_from_slice(&data[72..flags_end]);
+
+        Ok(Self {
+            stablecoin: Pubkey::try_from(&data[8..40]).unwrap(),
+            holder: Pubkey::try_from(&data[40..72]).unwrap(),
+            roles: RoleFlags::try_from_slice(&flags)?,
+            bump: data[flags_end],
+        })
+    }
+
+    /// Roles in force right now. The stored flags are authoritative as long as
+    /// roles carry no expiry or suspension state.
+    pub fn effective_roles(&self) -> RoleFlags {
+        self.roles
+    }
 }
 
 /// Bitflag roles for gas-efficient storage.
@@ -75,10 +440,57 @@ pub struct RoleFlags {
     pub is_pauser: bool,
     pub is_blacklister: bool,
     pub is_seizer: bool,
+    /// Blacklist namespace (e.g. sanctions vs fraud team) the holder adds
+    /// entries under and may remove entries from
+    pub blacklist_namespace: u8,
+    /// May change the token's name and URI via `update_metadata`
+    pub is_metadata_updater: bool,
 }
 
 impl RoleFlags {
-    pub const LEN: usize = 5; // 5 booleans
+    pub const LEN: usize = 5 + 1 + 1; // 5 booleans + blacklist_namespace + is_metadata_updater
+
+    pub const MINTER: u8 = 1 << 0;
+    pub const BURNER: u8 = 1 << 1;
+    pub const PAUSER: u8 = 1 << 2;
+    pub const BLACKLISTER: u8 = 1 << 3;
+    pub const SEIZER: u8 = 1 << 4;
+    pub const METADATA_UPDATER: u8 = 1 << 5;
+
+    /// Packs the flags into a single byte using the role bit constants.
+    pub fn to_bits(&self) -> u8 {
+        [
+            (self.is_minter, Self::MINTER),
+            (self.is_burner, Self::BURNER),
+            (self.is_pauser, Self::PAUSER),
+            (self.is_blacklister, Self::BLACKLISTER),
+            (self.is_seizer, Self::SEIZER),
+            (self.is_metadata_updater, Self::METADATA_UPDATER),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+
+    /// Number of roles set.
+    pub fn count(&self) -> u8 {
+        self.to_bits().count_ones() as u8
+    }
+
+    /// Sets the single role named by `bit` (one of the role bit constants).
+    pub fn set_bit(&mut self, bit: u8, value: bool) -> Result<()> {
+        let flag = match bit {
+            Self::MINTER => &mut self.is_minter,
+            Self::BURNER => &mut self.is_burner,
+            Self::PAUSER => &mut self.is_pauser,
+            Self::BLACKLISTER => &mut self.is_blacklister,
+            Self::SEIZER => &mut self.is_seizer,
+            Self::METADATA_UPDATER => &mut self.is_metadata_updater,
+            _ => return err!(StablecoinError::InvalidRoleConfig),
+        };
+        *flag = value;
+        Ok(())
+    }
 }
 
 /// Per-minter quota tracking PDA.
@@ -91,6 +503,12 @@ pub struct MinterInfo {
     pub quota: u64,
     /// Running total of tokens minted by this minter
     pub minted_amount: u64,
+    /// Quota reset period in seconds (0 = quota applies to the lifetime total)
+    pub epoch_duration: i64,
+    /// Start of the current epoch; never after the clock (see `roll_epoch`)
+    pub epoch_start: i64,
+    /// Tokens minted in the current epoch
+    pub minted_this_epoch: u64,
     pub bump: u8,
 }
 
@@ -100,6 +518,103 @@ impl MinterInfo {
         + 32                    // minter
         + 8                     // quota
         + 8                     // minted_amount
+        + 8                     // epoch_duration
+        + 8                     // epoch_start
+        + 8                     // minted_this_epoch
+        + 1;                    // bump
+
+    /// Size of minter accounts created before quota epochs, where `bump`
+    /// directly followed `minted_amount`.
+    pub const LEGACY_LEN: usize = Self::LEN - 8 - 8 - 8;
+
+    /// Decodes a minter account written with the current layout or the one
+    /// before quota epochs. The epoch fields of an old account read as zero,
+    /// i.e. a lifetime quota.
+    pub fn decode_any_version(data: &[u8]) -> Result<Self> {
+        if data.len() != Self::LEGACY_LEN {
+            return Self::try_deserialize(&mut &data[..]);
+        }
+        require!(data[..8] == Self::DISCRIMINATOR, ErrorCode::AccountDidNotDeserialize);
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(Self {
+            stablecoin: Pubkey::try_from(&data[8..40]).unwrap(),
+            minter: Pubkey::try_from(&data[40..72]).unwrap(),
+            quota: read_u64(72),
+            minted_amount: read_u64(80),
+            epoch_duration: 0,
+            epoch_start: 0,
+            minted_this_epoch: 0,
+            bump: data[88],
+        })
+    }
+
+    /// Amount counted against `quota`: the current epoch's mints when epochs
+    /// are enabled, otherwise the lifetime total.
+    pub fn quota_used(&self) -> u64 {
+        if self.epoch_duration > 0 {
+            self.minted_this_epoch
+        } else {
+            self.minted_amount
+        }
+    }
+
+    /// Start of an epoch beginning at `now`: `now` itself, or with an
+    /// alignment offset the latest `offset + k * epoch_duration` boundary.
+    pub fn epoch_start_at(&self, now: i64, alignment: Option<i64>) -> i64 {
+        match alignment {
+            Some(offset) if self.epoch_duration > 0 => {
+                now - now.saturating_sub(offset).rem_euclid(self.epoch_duration)
+            }
+            _ => now,
+        }
+    }
+
+    /// Starts a new epoch once the current one has elapsed.
+    pub fn roll_epoch(&mut self, now: i64, alignment: Option<i64>) {
+        // A start in the future (clock skew, bad data) would push the next
+        // reset out by the skew; clamp it to the epoch containing `now`. The
+        // usage is kept, so clamping never frees quota.
+        if self.epoch_duration > 0 && self.epoch_start > now {
+            self.epoch_start = self.epoch_start_at(now, alignment);
+        }
+        if self.epoch_duration > 0 && now >= self.epoch_start.saturating_add(self.epoch_duration) {
+            self.epoch_start = self.epoch_start_at(now, alignment);
+            self.minted_this_epoch = 0;
+        }
+    }
+}
+
+/// Immutable record of a minter's counters at the close of a reporting period.
+/// Seeds: [b"minter_stats", stablecoin.key().as_ref(), minter.key().as_ref(), period.to_le_bytes()]
+#[account]
+pub struct MinterStatsSnapshot {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    /// Issuer-chosen period identifier (e.g. 202603 for Q3 2026)
+    pub period: u64,
+    pub quota: u64,
+    /// `minted_amount` at the time of the snapshot
+    pub minted_amount: u64,
+    pub minted_this_epoch: u64,
+    /// Whether the live `minted_amount` was reset after the snapshot
+    pub reset: bool,
+    pub taken_at: i64,
+    pub taken_by: Pubkey,
+    pub bump: u8,
+}
+
+impl MinterStatsSnapshot {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // stablecoin
+        + 32                    // minter
+        + 8                     // period
+        + 8                     // quota
+        + 8                     // minted_amount
+        + 8                     // minted_this_epoch
+        + 1                     // reset
+        + 8                     // taken_at
+        + 32                    // taken_by
         + 1;                    // bump
 }
 
@@ -113,14 +628,232 @@ pub struct BlacklistEntry {
     pub blacklisted_at: i64,
     pub blacklisted_by: Pubkey,
     pub bump: u8,
+    /// Team namespace of the blacklister that created the entry
+    pub namespace: u8,
+    /// End of a temporary blacklisting; the entry stops applying once the
+    /// clock passes it (None = until removed)
+    pub expires_at: Option<i64>,
+    pub reason_code: BlacklistReasonCode,
+    // `namespace`, `expires_at` and `reason_code` were appended after entries
+    // already existed. Older entries are shorter than `LEN`, and whether the
+    // new fields fit in their zeroed reason padding depends on the reason's
+    // length, so they are decoded with `decode_any_version` and grown with
+    // `migrate_blacklist_entry`.
 }
 
 impl BlacklistEntry {
+    /// Byte offset of `stablecoin`, directly after the discriminator. Part of
+    /// the indexer contract: new fields are only ever appended after
+    /// `namespace`, so this and `ADDRESS_OFFSET` never move.
+    pub const STABLECOIN_OFFSET: usize = 8;
+    pub const ADDRESS_OFFSET: usize = Self::STABLECOIN_OFFSET + 32;
+
     pub const LEN: usize = 8   // discriminator
         + 32                    // stablecoin
         + 32                    // address
         + (4 + MAX_REASON_LEN)  // reason
         + 8                     // blacklisted_at
         + 32                    // blacklisted_by
+        + 1                     // bump
+        + 1                     // namespace
+        + (1 + 8)               // expires_at
+        + 1;                    // reason_code
+
+    /// Size of entries created before `namespace`, `expires_at` and
+    /// `reason_code` were appended. Entries between this and `LEN` were
+    /// written with a prefix of those fields.
+    pub const LEGACY_LEN: usize = Self::LEN - 1 - (1 + 8) - 1;
+
+    /// Decodes an entry written with any earlier layout. Fields missing from
+    /// the stored data read as zero: namespace 0, no expiry, `Other`.
+    pub fn decode_any_version(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= Self::LEGACY_LEN
+                && data.len() <= Self::LEN
+                && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::AccountDidNotDeserialize
+        );
+        let mut padded = [0u8; Self::LEN];
+        padded[..data.len()].copy_from_slice(data);
+        Self::try_deserialize(&mut &padded[..])
+    }
+
+    /// Whether the entry is in force at `now`. The transfer hook applies the
+    /// same rule to the raw account bytes.
+    pub fn blacklist_active(&self, now: i64) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now <= expires_at,
+            None => true,
+        }
+    }
+
+    /// `getProgramAccounts` memcmp bytes, applied at offset 0, that match
+    /// every blacklist entry of `stablecoin`: the discriminator followed by
+    /// the stablecoin key. The discriminator keeps other PDAs that also start
+    /// with a stablecoin key (roles, minters, mint locks) out of the results.
+    pub fn stablecoin_filter(stablecoin: &Pubkey) -> [u8; 40] {
+        let mut bytes = [0u8; 40];
+        bytes[..Self::STABLECOIN_OFFSET].copy_from_slice(&Self::DISCRIMINATOR);
+        bytes[Self::STABLECOIN_OFFSET..].copy_from_slice(stablecoin.as_ref());
+        bytes
+    }
+}
+
+/// Holding period of a token account that received minted tokens. Coarse by
+/// design: every mint restarts the lock on the whole account, not per lot.
+/// Seeds: [b"mint_lock", stablecoin.key().as_ref(), token_account.key().as_ref()]
+#[account]
+pub struct MintLock {
+    pub stablecoin: Pubkey,
+    pub token_account: Pubkey,
+    pub last_mint_at: i64,
+    /// The transfer hook rejects transfers out of `token_account` until then
+    pub locked_until: i64,
+    pub bump: u8,
+}
+
+impl MintLock {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // stablecoin
+        + 32                    // token_account
+        + 8                     // last_mint_at
+        + 8                     // locked_until
         + 1;                    // bump
 }
+
+/// Opt-in record of the symbols an authority has issued, used to stop the
+/// same issuer from reusing a symbol.
+/// Seeds: [b"issuer_registry", authority.key().as_ref()]
+#[account]
+pub struct IssuerRegistry {
+    pub authority: Pubkey,
+    pub symbols: Vec<String>,
+    pub bump: u8,
+}
+
+impl IssuerRegistry {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // authority
+        + 4 + MAX_REGISTRY_SYMBOLS * (4 + MAX_SYMBOL_LEN) // symbols
+        + 1;                    // bump
+
+    /// Records `symbol`, rejecting one already used (ignoring ASCII case).
+    pub fn register(&mut self, symbol: &str) -> Result<()> {
+        require!(
+            !self.symbols.iter().any(|used| used.eq_ignore_ascii_case(symbol)),
+            StablecoinError::SymbolAlreadyUsed
+        );
+        require!(self.symbols.len() < MAX_REGISTRY_SYMBOLS, StablecoinError::IssuerRegistryFull);
+        self.symbols.push(symbol.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A state with every field zeroed (unpaused, burns blocked while paused).
+    fn stablecoin(paused: bool, allow_burn_when_paused: bool) -> StablecoinState {
+        let zeroed = [0u8; StablecoinState::LEN];
+        let mut state = StablecoinState::deserialize(&mut &zeroed[..]).unwrap();
+        state.paused = paused;
+        state.allow_burn_when_paused = allow_burn_when_paused;
+        state
+    }
+
+    #[test]
+    fn decodes_original_layout() {
+        let legacy = LegacyStablecoinState {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            name: "Legacy USD".to_string(),
+            symbol: "LUSD".to_string(),
+            uri: String::new(),
+            decimals: 6,
+            enable_permanent_delegate: true,
+            enable_transfer_hook: true,
+            default_account_frozen: false,
+            paused: true,
+            total_minted: 5_000,
+            total_burned: 1_000,
+            bump: 254,
+        };
+        let mut data = vec![0u8; StablecoinState::LEGACY_LEN];
+        data[..8].copy_from_slice(&StablecoinState::DISCRIMINATOR);
+        legacy.serialize(&mut &mut data[8..]).unwrap();
+
+        // The old bump sits where `mint_introspection` is now expected
+        assert!(StablecoinState::try_deserialize(&mut &data[..]).is_err());
+
+        let state = StablecoinState::decode_any_version(&data).unwrap();
+        assert_eq!(state.authority, legacy.authority);
+        assert_eq!(state.mint, legacy.mint);
+        assert_eq!(state.name, "Legacy USD");
+        assert_eq!(state.symbol, "LUSD");
+        assert!(state.is_sss2());
+        assert!(state.is_paused());
+        assert_eq!(state.circulating_supply().unwrap(), 4_000);
+        assert_eq!(state.bump, 254);
+        assert_eq!(state.freeze_authority_seeds(), (STABLECOIN_SEED, 254));
+        assert_eq!(state.display_decimals, 6);
+        assert_eq!(state.hook_mode, HookMode::Enforce);
+
+        // The migrated account round-trips through the current layout
+        let mut migrated = vec![0u8; StablecoinState::LEN];
+        state.try_serialize(&mut &mut migrated[..]).unwrap();
+        let reread = StablecoinState::decode_any_version(&migrated).unwrap();
+        assert_eq!(reread.bump, 254);
+        assert_eq!(reread.total_minted, 5_000);
+    }
+
+    #[test]
+    fn decodes_minter_without_epochs() {
+        let stablecoin = Pubkey::new_unique();
+        let minter = Pubkey::new_unique();
+        let mut data = Vec::with_capacity(MinterInfo::LEGACY_LEN);
+        data.extend_from_slice(&MinterInfo::DISCRIMINATOR);
+        data.extend_from_slice(stablecoin.as_ref());
+        data.extend_from_slice(minter.as_ref());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&250u64.to_le_bytes());
+        data.push(253);
+        assert_eq!(data.len(), MinterInfo::LEGACY_LEN);
+        assert!(MinterInfo::try_deserialize(&mut &data[..]).is_err());
+
+        let info = MinterInfo::decode_any_version(&data).unwrap();
+        assert_eq!(info.stablecoin, stablecoin);
+        assert_eq!(info.minter, minter);
+        assert_eq!(info.quota, 1_000);
+        assert_eq!(info.minted_amount, 250);
+        assert_eq!(info.epoch_duration, 0);
+        assert_eq!(info.quota_used(), 250);
+        assert_eq!(info.bump, 253);
+    }
+
+    #[test]
+    fn unpaused_allows_every_operation() {
+        for allow_burn_when_paused in [false, true] {
+            let state = stablecoin(false, allow_burn_when_paused);
+            assert!(!state.is_paused());
+            assert!(state.is_operation_allowed(OperationKind::Mint));
+            assert!(state.is_operation_allowed(OperationKind::Burn));
+        }
+    }
+
+    #[test]
+    fn paused_blocks_mints_and_burns() {
+        let state = stablecoin(true, false);
+        assert!(state.is_paused());
+        assert!(!state.is_operation_allowed(OperationKind::Mint));
+        assert!(!state.is_operation_allowed(OperationKind::Burn));
+    }
+
+    #[test]
+    fn paused_with_burn_exemption_allows_only_burns() {
+        let state = stablecoin(true, true);
+        assert!(state.is_paused());
+        assert!(!state.is_operation_allowed(OperationKind::Mint));
+        assert!(state.is_operation_allowed(OperationKind::Burn));
+    }
+}
@@ -8,4 +8,22 @@ pub enum HookError {
     Paused,
     #[msg("Invalid instruction discriminator for transfer hook")]
     InvalidInstruction,
+    #[msg("Transfer denied: recently minted tokens are still locked")]
+    MintLocked,
+    #[msg("Transfer denied: destination account is not rent-exempt")]
+    DestinationNotRentExempt,
+    #[msg("ExtraAccountMetaList is already initialized for this mint")]
+    AlreadyInitialized,
+    #[msg("ExtraAccountMetaList does not start with the sss-token program")]
+    InvalidMetaList,
+    #[msg("Signer is not the stablecoin authority")]
+    Unauthorized,
+    #[msg("Transfer denied: amount exceeds the per-transfer limit")]
+    TransferLimitExceeded,
+    #[msg("Transfer denied: source would keep less than the minimum balance")]
+    BelowMinHoldBalance,
+    #[msg("Transfer denied: destination belongs to a denied integration")]
+    DeniedIntegration,
+    #[msg("Execute instruction data must be the discriminator followed by a u64 amount")]
+    InvalidExecuteData,
 }
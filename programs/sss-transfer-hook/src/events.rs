@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Emitted in monitor mode for a transfer the blacklist would have rejected.
+#[event]
+pub struct TransferDenied {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    /// Blacklisted wallet that triggered the decision
+    pub blacklisted: Pubkey,
+}
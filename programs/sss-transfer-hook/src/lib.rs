@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::System;
+use anchor_lang::system_program::{self, System};
 use anchor_lang::solana_program::program::invoke_signed;
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 use spl_tlv_account_resolution::{
     account::ExtraAccountMeta,
+    pubkey_data::PubkeyData,
     seeds::Seed,
     state::ExtraAccountMetaList,
 };
 use spl_discriminator::discriminator::SplDiscriminate;
+use spl_type_length_value::state::TlvStateBorrowed;
 
 pub mod error;
+pub mod events;
 use error::HookError;
+use events::TransferDenied;
 
 declare_id!("F2of7agMFET8v3verXe3e6Hmfd71t833RjPxEjs5wRdd");
 
@@ -34,53 +38,18 @@ pub mod sss_transfer_hook {
     ///   [6] stablecoin state PDA: seeds=[b"stablecoin", mint(1)] under program(5)
     ///   [7] source blacklist PDA: seeds=[b"blacklist", stablecoin(6), authority(3)] under program(5)
     ///   [8] dest blacklist PDA:   seeds=[b"blacklist", stablecoin(6), dest_owner_from_data(2,32,32)] under program(5)
+    ///   [9] source mint lock PDA: seeds=[b"mint_lock", stablecoin(6), source(0)] under program(5)
+    ///   [10] destination owner: pubkey read from destination(2) data bytes 32..64
     pub fn initialize_extra_account_meta_list(
         ctx: Context<InitializeExtraAccountMetas>,
         sss_token_program_id: Pubkey,
     ) -> Result<()> {
-        // Order matters: each account can only reference earlier accounts.
-        let extra_account_metas = vec![
-            // [5] sss-token program ID (literal, no dependencies)
-            ExtraAccountMeta::new_with_pubkey(&sss_token_program_id, false, false)?,
-
-            // [6] Stablecoin state PDA: seeds=[b"stablecoin", mint_key]
-            //     External PDA owned by sss-token program (index 5)
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5, // program at index 5
-                &[
-                    Seed::Literal { bytes: b"stablecoin".to_vec() },
-                    Seed::AccountKey { index: 1 }, // mint
-                ],
-                false,
-                false,
-            )?,
-
-            // [7] Source blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, source_authority]
-            //     May or may not exist; if it does, source is blacklisted
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 6 }, // stablecoin state
-                    Seed::AccountKey { index: 3 }, // authority (source wallet)
-                ],
-                false,
-                false,
-            )?,
-
-            // [8] Destination blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, dest_owner]
-            //     dest_owner extracted from destination token account data bytes 32..64
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 6 }, // stablecoin state
-                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // dest owner
-                ],
-                false,
-                false,
-            )?,
-        ];
+        // create_account would also refuse, but with an opaque System error
+        if !ctx.accounts.extra_account_meta_list.data_is_empty() {
+            return Err(HookError::AlreadyInitialized.into());
+        }
+
+        let extra_account_metas = extra_account_metas(&sss_token_program_id)?;
 
         // Calculate required account size
         let account_size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
@@ -116,6 +85,70 @@ pub mod sss_transfer_hook {
         Ok(())
     }
 
+    /// Rewrites an existing ExtraAccountMetaList with the current set of extra
+    /// accounts, so mints created before a new account was added (e.g. the
+    /// mint lock) pick it up without being recreated. Only the stablecoin
+    /// authority may refresh, and the sss-token program ID is carried over
+    /// from the existing list so a refresh cannot point the hook elsewhere.
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetas>) -> Result<()> {
+        let meta_list = &ctx.accounts.extra_account_meta_list;
+        let sss_token_program_id = {
+            let data = meta_list.try_borrow_data()?;
+            let state = TlvStateBorrowed::unpack(&data)?;
+            let metas = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?;
+            let program = metas.data().first().ok_or(HookError::InvalidMetaList)?;
+            Pubkey::new_from_array(program.address_config)
+        };
+
+        // The signer must be the authority stored in the stablecoin state:
+        //   8 bytes discriminator, then authority (Pubkey)
+        let stablecoin = &ctx.accounts.stablecoin;
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"stablecoin", ctx.accounts.mint.key().as_ref()],
+            &sss_token_program_id,
+        );
+        require!(
+            stablecoin.key() == expected && *stablecoin.owner == sss_token_program_id,
+            HookError::Unauthorized
+        );
+        require!(
+            stablecoin.try_borrow_data()?.get(8..40) == Some(ctx.accounts.authority.key().as_ref()),
+            HookError::Unauthorized
+        );
+
+        let extra_account_metas = extra_account_metas(&sss_token_program_id)?;
+        let account_size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+        let shortfall = Rent::get()?
+            .minimum_balance(account_size)
+            .saturating_sub(meta_list.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: meta_list.to_account_info(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+
+        // Grow before rewriting, shrink after, so the TLV entry always fits
+        if account_size > meta_list.data_len() {
+            meta_list.realloc(account_size, false)?;
+        }
+        ExtraAccountMetaList::update::<ExecuteInstruction>(
+            &mut meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+        if account_size < meta_list.data_len() {
+            meta_list.realloc(account_size, false)?;
+        }
+
+        Ok(())
+    }
+
     /// Fallback handler — Token-2022 CPIs here on every transfer.
     /// Verifies the Execute discriminator, checks pause status, and checks blacklist.
     pub fn fallback<'info>(
@@ -123,18 +156,19 @@ pub mod sss_transfer_hook {
         accounts: &'info [AccountInfo<'info>],
         data: &[u8],
     ) -> Result<()> {
-        // Verify Execute instruction discriminator
-        if data.len() < 8 {
-            return Err(HookError::InvalidInstruction.into());
-        }
-        let discriminator = &data[..8];
-        if discriminator != ExecuteInstruction::SPL_DISCRIMINATOR_SLICE {
-            return Err(HookError::InvalidInstruction.into());
-        }
+        // Execute is the only instruction served here; anything else fails
+        // before any account is touched.
+        let amount = parse_execute_amount(data)?;
 
         // Accounts layout:
         // [0] source, [1] mint, [2] dest, [3] authority, [4] extra_meta_list
         // [5] sss-token program, [6] stablecoin state, [7] source blacklist, [8] dest blacklist
+        // [9] source mint lock (absent from meta lists created before mint locks)
+
+        // The stablecoin PDA only signs transfers as the permanent delegate,
+        // i.e. seizures. Those must get past the checks that exist to stop
+        // the (blacklisted, locked) source's owner.
+        let delegate_transfer = accounts.len() > 6 && accounts[3].key == accounts[6].key;
 
         // Check pause: read the `paused` flag from the stablecoin state PDA.
         // The flag is embedded in a Borsh-serialized struct with variable-length
@@ -144,21 +178,123 @@ pub mod sss_transfer_hook {
             if read_paused_flag(&stablecoin_data) {
                 return Err(HookError::Paused.into());
             }
+
+            // Freshly minted tokens stay put until the source's lock expires
+            if accounts.len() > 9 && !delegate_transfer {
+                if let Some(locked_until) = read_locked_until(&accounts[9], accounts[5].key) {
+                    if Clock::get()?.unix_timestamp < locked_until {
+                        return Err(HookError::MintLocked.into());
+                    }
+                }
+            }
         }
 
-        // Check blacklist: if the PDA account has data, the address is blacklisted
-        if accounts.len() > 7 {
-            let source_blacklist = &accounts[7];
-            if source_blacklist.data_len() > 0 && **source_blacklist.try_borrow_lamports()? > 0 {
-                return Err(HookError::Blacklisted.into());
+        if delegate_transfer {
+            return Ok(());
+        }
+
+        // Holder transfers only: a seizure must not be blocked by where the
+        // treasury happens to keep its funds.
+        if accounts.len() > 6 && read_require_rent_exempt(&accounts[6].try_borrow_data()?) {
+            let dest = &accounts[2];
+            if !Rent::get()?.is_exempt(dest.lamports(), dest.data_len()) {
+                return Err(HookError::DestinationNotRentExempt.into());
             }
         }
 
-        if accounts.len() > 8 {
-            let dest_blacklist = &accounts[8];
-            if dest_blacklist.data_len() > 0 && **dest_blacklist.try_borrow_lamports()? > 0 {
+        // In monitor mode blacklist hits are logged instead of rejected, so a
+        // new ruleset can be validated against live traffic first.
+        let monitor = accounts.len() > 6 && read_monitor_mode(&accounts[6].try_borrow_data()?);
+        let deny = |blacklisted: Pubkey| -> Result<()> {
+            if !monitor {
                 return Err(HookError::Blacklisted.into());
             }
+            emit!(TransferDenied {
+                mint: accounts[1].key(),
+                source: accounts[0].key(),
+                destination: accounts[2].key(),
+                blacklisted,
+            });
+            Ok(())
+        };
+
+        // Check blacklist: if the PDA account has data, the address is
+        // blacklisted, unless the entry was temporary and has expired
+        let now = Clock::get()?.unix_timestamp;
+        let listed = |entry: &AccountInfo| -> Result<bool> {
+            Ok(entry.data_len() > 0
+                && **entry.try_borrow_lamports()? > 0
+                && blacklist_entry_active(&entry.try_borrow_data()?, now))
+        };
+        if accounts.len() > 7 && listed(&accounts[7])? {
+            deny(accounts[3].key())?;
+        }
+
+        if accounts.len() > 6 {
+            let stablecoin_data = accounts[6].try_borrow_data()?;
+
+            // Transfers into the configured burn sink are destructions, not
+            // payments — skip the destination checks below. A blacklisted
+            // source was already stopped above.
+            if let Some(burn_address) = read_burn_address(&stablecoin_data) {
+                let dest_data = accounts[2].try_borrow_data()?;
+                if dest_data.len() >= 64 && dest_data[32..64] == burn_address.to_bytes() {
+                    return Ok(());
+                }
+            }
+
+            let max_transfer = read_max_transfer_per_tx(&stablecoin_data);
+            if max_transfer > 0 && amount > max_transfer {
+                return Err(HookError::TransferLimitExceeded.into());
+            }
+
+            // The hook runs after Token-2022 has moved the tokens, so the
+            // source already holds its post-transfer balance: either empty
+            // it or keep at least the minimum.
+            let min_hold = read_min_hold_balance(&stablecoin_data);
+            if min_hold > 0 {
+                let remaining = accounts[0]
+                    .try_borrow_data()?
+                    .get(64..72)
+                    .and_then(|bytes| <[u8; 8]>::try_from(bytes).ok())
+                    .map(u64::from_le_bytes)
+                    .unwrap_or(0);
+                if remaining > 0 && remaining < min_hold {
+                    return Err(HookError::BelowMinHoldBalance.into());
+                }
+            }
+
+            // Matched on the destination token account's owner (a vault
+            // authority) or on the program owning that account. Lists created
+            // before [10] existed only get the address match.
+            let denied = read_denied_integrations(&stablecoin_data);
+            if !denied.is_empty() {
+                let dest_data = accounts[2].try_borrow_data()?;
+                let dest_owner = dest_data
+                    .get(32..64)
+                    .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                    .map(Pubkey::new_from_array)
+                    .unwrap_or_default();
+                let owner_program = accounts
+                    .get(10)
+                    .filter(|account| *account.key == dest_owner)
+                    .map(|account| *account.owner);
+                if denied.contains(&dest_owner)
+                    || owner_program.is_some_and(|program| denied.contains(&program))
+                {
+                    return Err(HookError::DeniedIntegration.into());
+                }
+            }
+        }
+
+        if accounts.len() > 8 && listed(&accounts[8])? {
+            let dest_data = accounts[2].try_borrow_data()?;
+            let dest_owner = dest_data
+                .get(32..64)
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .map(Pubkey::new_from_array)
+                .unwrap_or_default();
+            deny(dest_owner)?;
         }
 
         // Transfer allowed
@@ -166,6 +302,87 @@ pub mod sss_transfer_hook {
     }
 }
 
+/// Extra accounts Token-2022 must pass to the hook on every transfer, in the
+/// order documented on `initialize_extra_account_meta_list`.
+fn extra_account_metas(sss_token_program_id: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    // Order matters: each account can only reference earlier accounts.
+    Ok(vec![
+        // [5] sss-token program ID (literal, no dependencies)
+        ExtraAccountMeta::new_with_pubkey(sss_token_program_id, false, false)?,
+
+        // [6] Stablecoin state PDA: seeds=[b"stablecoin", mint_key]
+        //     External PDA owned by sss-token program (index 5)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5, // program at index 5
+            &[
+                Seed::Literal { bytes: b"stablecoin".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+
+        // [7] Source blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, source_authority]
+        //     May or may not exist; if it does, source is blacklisted
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"blacklist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountKey { index: 3 }, // authority (source wallet)
+            ],
+            false,
+            false,
+        )?,
+
+        // [8] Destination blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, dest_owner]
+        //     dest_owner extracted from destination token account data bytes 32..64
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"blacklist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // dest owner
+            ],
+            false,
+            false,
+        )?,
+
+        // [9] Source mint lock PDA: seeds=[b"mint_lock", stablecoin_key, source]
+        //     Only exists once the source received a mint under a mint lock
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"mint_lock".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountKey { index: 0 }, // source token account
+            ],
+            false,
+            false,
+        )?,
+
+        // [10] Destination owner: the wallet or vault authority in destination
+        //      token account data bytes 32..64, so the hook can see which
+        //      program owns it
+        ExtraAccountMeta::new_with_pubkey_data(
+            &PubkeyData::AccountData { account_index: 2, data_index: 32 },
+            false,
+            false,
+        )?,
+    ])
+}
+
+/// Parse SPL Transfer Hook `Execute` data: discriminator(8) + amount(u64 LE).
+/// Any other discriminator, or Execute data of the wrong length, is rejected.
+fn parse_execute_amount(data: &[u8]) -> Result<u64> {
+    if data.len() < 8 || &data[..8] != ExecuteInstruction::SPL_DISCRIMINATOR_SLICE {
+        msg!("Unrecognized transfer hook instruction: {:?}", &data[..data.len().min(8)]);
+        return Err(HookError::InvalidInstruction.into());
+    }
+    let amount = <[u8; 8]>::try_from(&data[8..]).map_err(|_| HookError::InvalidExecuteData)?;
+    Ok(u64::from_le_bytes(amount))
+}
+
 /// Read the `paused` flag from a Borsh-serialized StablecoinState account.
 ///
 /// Layout:
@@ -181,28 +398,205 @@ pub mod sss_transfer_hook {
 ///   1  byte  — default_account_frozen
 ///   1  byte  — paused  ← this is what we read
 fn read_paused_flag(data: &[u8]) -> bool {
+    match paused_offset(data) {
+        Some(offset) if offset < data.len() => data[offset] != 0,
+        _ => false,
+    }
+}
+
+/// Read the optional `burn_address` from a StablecoinState account.
+///
+/// Continues the layout after `paused`:
+///   8  bytes — total_minted
+///   8  bytes — total_burned
+///   1  byte  — mint_introspection
+///   1  byte  — separate_freeze_authority
+///   1  byte  — freeze_authority_bump
+///   32 bytes — burn_address  ← returned unless unset (default pubkey)
+fn read_burn_address(data: &[u8]) -> Option<Pubkey> {
+    let offset = burn_address_offset(data)?;
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    let burn_address = Pubkey::new_from_array(bytes);
+    (burn_address != Pubkey::default()).then_some(burn_address)
+}
+
+/// Whether `hook_mode` (0 = Enforce, 1 = Monitor) is Monitor.
+///
+/// Accounts too short to hold the field are treated as Enforce.
+fn read_monitor_mode(data: &[u8]) -> bool {
+    let read = || -> Option<bool> { Some(*data.get(hook_mode_offset(data)?)? == 1) };
+    read().unwrap_or(false)
+}
+
+/// Read `require_rent_exempt_destination`. Continues after `hook_mode`:
+///   4 + 32n  — freeze_protected (Vec<Pubkey>)
+///   2  bytes — max_uri_len
+///   2  bytes — seize_fee_bps
+///   32 bytes — seize_fee_treasury
+///   1  byte  — max_roles_per_holder
+///   1  byte  — restrict_redemptions
+///   4 + 32n  — redemption_accounts (Vec<Pubkey>)
+///   8  bytes — mint_lock_duration
+///   8  bytes — blacklist_count
+///   8  bytes — total_seized
+///   8  bytes — seizure_count
+///   1 (+32)  — reserve_oracle (Option<Pubkey>)
+///   2  bytes — min_reserve_ratio_bps
+///   1 (+32)  — recovery_authority (Option<Pubkey>)
+///   8  bytes — recovery_inactivity_period
+///   8  bytes — last_authority_action
+///   1  byte  — require_rent_exempt_destination  ← this is what we read
+///
+/// Accounts too short to hold the field are treated as not requiring it.
+fn read_require_rent_exempt(data: &[u8]) -> bool {
+    let read = || -> Option<bool> { Some(*data.get(rent_exempt_offset(data)?)? != 0) };
+    read().unwrap_or(false)
+}
+
+/// Read `max_transfer_per_tx`. Continues after `require_rent_exempt_destination`:
+///   8  bytes — max_seize_per_tx
+///   1  byte  — allow_burn_when_paused
+///   8  bytes — max_transfer_per_tx  ← this is what we read
+///
+/// Accounts too short to hold the field have no limit (0).
+fn read_max_transfer_per_tx(data: &[u8]) -> u64 {
+    read_u64_after_rent_exempt(data, 1 + 8 + 1)
+}
+
+/// Read `min_hold_balance`, the 8 bytes after `max_transfer_per_tx`.
+///
+/// Accounts too short to hold the field have no minimum (0).
+fn read_min_hold_balance(data: &[u8]) -> u64 {
+    read_u64_after_rent_exempt(data, 1 + 8 + 1 + 8)
+}
+
+/// Read `denied_integrations` (Vec<Pubkey>), after `min_hold_balance` and
+/// the 1-byte `display_decimals`.
+///
+/// Accounts too short to hold the field deny nothing.
+fn read_denied_integrations(data: &[u8]) -> Vec<Pubkey> {
+    let read = || -> Option<Vec<Pubkey>> {
+        let offset = rent_exempt_offset(data)? + 1 + 8 + 1 + 8 + 8 + 1;
+        let len = (vec_len(data, offset)? - 4) / 32;
+        (0..len)
+            .map(|i| {
+                let start = offset + 4 + 32 * i;
+                let bytes: [u8; 32] = data.get(start..start + 32)?.try_into().ok()?;
+                Some(Pubkey::new_from_array(bytes))
+            })
+            .collect()
+    };
+    read().unwrap_or_default()
+}
+
+fn read_u64_after_rent_exempt(data: &[u8], skip: usize) -> u64 {
+    let read = || -> Option<u64> {
+        let offset = rent_exempt_offset(data)? + skip;
+        let bytes: [u8; 8] = data.get(offset..offset + 8)?.try_into().ok()?;
+        Some(u64::from_le_bytes(bytes))
+    };
+    read().unwrap_or(0)
+}
+
+fn rent_exempt_offset(data: &[u8]) -> Option<usize> {
+    let mut offset = hook_mode_offset(data)? + 1;
+    offset += vec_len(data, offset)?;
+    offset += 2 + 2 + 32 + 1 + 1;
+    offset += vec_len(data, offset)?;
+    offset += 8 * 4;
+    offset += option_len(data, offset)?;
+    offset += 2;
+    offset += option_len(data, offset)?;
+    Some(offset + 8 + 8)
+}
+
+/// Offset of `hook_mode`, after `burn_address`:
+///   1  byte  — event_mask
+///   8  bytes — max_single_mint
+///   1 (+32)  — pending_authority (Option<Pubkey>)
+///   8  bytes — pending_since
+///   8  bytes — authority_transfer_cooldown
+fn hook_mode_offset(data: &[u8]) -> Option<usize> {
+    let mut offset = burn_address_offset(data)? + 32 + 1 + 8;
+    offset += option_len(data, offset)?;
+    Some(offset + 8 + 8)
+}
+
+/// Serialized size of the Borsh `Vec<Pubkey>` at `offset`.
+fn vec_len(data: &[u8], offset: usize) -> Option<usize> {
+    let len_bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+    Some(4 + 32 * u32::from_le_bytes(len_bytes) as usize)
+}
+
+/// Serialized size of the Borsh `Option<Pubkey>` at `offset`.
+fn option_len(data: &[u8], offset: usize) -> Option<usize> {
+    Some(match *data.get(offset)? {
+        0 => 1,
+        _ => 1 + 32,
+    })
+}
+
+/// Whether a BlacklistEntry is in force at `now`, mirroring
+/// `BlacklistEntry::blacklist_active` in sss-token:
+///   8  bytes — Anchor discriminator
+///   32 bytes — stablecoin (Pubkey)
+///   32 bytes — address (Pubkey)
+///   4 + N    — reason (String)
+///   8  bytes — blacklisted_at
+///   32 bytes — blacklisted_by
+///   1  byte  — bump
+///   1  byte  — namespace
+///   1 (+8)   — expires_at (Option<i64>)  ← this is what we read
+///
+/// Entries without an expiry, or too short to hold one, stay in force.
+fn blacklist_entry_active(data: &[u8], now: i64) -> bool {
+    let read = || -> Option<i64> {
+        let len_bytes: [u8; 4] = data.get(72..76)?.try_into().ok()?;
+        let offset = 76 + u32::from_le_bytes(len_bytes) as usize + 8 + 32 + 1 + 1;
+        if *data.get(offset)? == 0 {
+            return None;
+        }
+        let bytes: [u8; 8] = data.get(offset + 1..offset + 9)?.try_into().ok()?;
+        Some(i64::from_le_bytes(bytes))
+    };
+    match read() {
+        Some(expires_at) => now <= expires_at,
+        None => true,
+    }
+}
+
+/// Read `locked_until` from a MintLock account owned by the sss-token program:
+///   8  bytes — Anchor discriminator
+///   32 bytes — stablecoin (Pubkey)
+///   32 bytes — token_account (Pubkey)
+///   8  bytes — last_mint_at (i64)
+///   8  bytes — locked_until (i64)  ← this is what we read
+fn read_locked_until(lock: &AccountInfo, sss_token_program: &Pubkey) -> Option<i64> {
+    if lock.owner != sss_token_program {
+        return None;
+    }
+    let data = lock.try_borrow_data().ok()?;
+    let bytes: [u8; 8] = data.get(80..88)?.try_into().ok()?;
+    Some(i64::from_le_bytes(bytes))
+}
+
+fn burn_address_offset(data: &[u8]) -> Option<usize> {
+    Some(paused_offset(data)? + 1 + 8 + 8 + 1 + 1 + 1)
+}
+
+/// Offset of the `paused` byte, after the variable-length strings.
+fn paused_offset(data: &[u8]) -> Option<usize> {
     // Skip discriminator + authority + mint
     let mut offset: usize = 8 + 32 + 32; // 72
 
     // Skip three variable-length Borsh strings (name, symbol, uri)
     for _ in 0..3 {
-        if data.len() < offset + 4 {
-            return false;
-        }
-        let str_len = u32::from_le_bytes(
-            data[offset..offset + 4].try_into().unwrap_or([0; 4]),
-        ) as usize;
-        offset += 4 + str_len;
+        let len_bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+        offset += 4 + u32::from_le_bytes(len_bytes) as usize;
     }
 
     // Skip decimals(1) + enable_permanent_delegate(1) + enable_transfer_hook(1) + default_account_frozen(1)
-    offset += 4;
-
-    // Read the paused byte
-    if data.len() <= offset {
-        return false;
-    }
-    data[offset] != 0
+    Some(offset + 4)
 }
 
 #[derive(Accounts)]
@@ -220,3 +614,28 @@ pub struct InitializeExtraAccountMetas<'info> {
 
     pub system_program: Program<'info, System>,
 }
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetas<'info> {
+    /// Stablecoin authority; pays for any growth of the list
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The existing ExtraAccountMetaList PDA — rewritten here
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// CHECK: The Token-2022 mint that has this hook attached
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: The mint's stablecoin state PDA; verified against the
+    /// sss-token program recorded in the list
+    pub stablecoin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
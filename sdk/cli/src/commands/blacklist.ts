@@ -15,7 +15,14 @@ export function builder(yargs: Argv) {
     .option("mint", { alias: "m", type: "string", demandOption: true, description: "Mint address" })
     .option("action", { type: "string", choices: ["add", "remove"] as const, demandOption: true })
     .option("address", { type: "string", demandOption: true, description: "Address to blacklist" })
-    .option("reason", { type: "string", default: "", description: "Reason for blacklisting" });
+    .option("reason", { type: "string", default: "", description: "Reason for blacklisting" })
+    .option("reason-code", {
+      type: "string",
+      choices: ["other", "sanctions", "fraud", "court-order"] as const,
+      default: "other",
+      description: "Category of the blacklisting, for compliance reporting",
+    })
+    .option("expires-at", { type: "number", description: "Unix timestamp after which the entry stops applying (default: until removed)" });
 }
 
 export async function handler(argv: ArgumentsCamelCase) {
@@ -35,14 +42,20 @@ export async function handler(argv: ArgumentsCamelCase) {
     const reasonBytes = Buffer.from((argv.reason as string) || "", "utf-8");
     const lenBuf = Buffer.alloc(4);
     lenBuf.writeUInt32LE(reasonBytes.length);
-    data = Buffer.concat([disc("add_to_blacklist"), lenBuf, reasonBytes]);
+    const expiry = Buffer.alloc(argv.expiresAt === undefined ? 1 : 9);
+    if (argv.expiresAt !== undefined) {
+      expiry[0] = 1;
+      expiry.writeBigInt64LE(BigInt(argv.expiresAt as number), 1);
+    }
+    const reasonCode = ["other", "sanctions", "fraud", "court-order"].indexOf(argv.reasonCode as string);
+    data = Buffer.concat([disc("add_to_blacklist"), lenBuf, reasonBytes, Buffer.from([reasonCode]), expiry]);
   } else {
     data = disc("remove_from_blacklist");
   }
 
   const keys = [
     { pubkey: blacklister.publicKey, isSigner: true, isWritable: true },
-    { pubkey: stablecoinPda, isSigner: false, isWritable: false },
+    { pubkey: stablecoinPda, isSigner: false, isWritable: true },
     { pubkey: rolePda, isSigner: false, isWritable: false },
     { pubkey: blacklistPda, isSigner: false, isWritable: true },
     { pubkey: targetAddress, isSigner: false, isWritable: false },
@@ -55,6 +68,7 @@ export async function handler(argv: ArgumentsCamelCase) {
 
   console.log(`\nAddress ${isAdd ? "added to" : "removed from"} blacklist!`);
   console.log(`  Address: ${targetAddress.toBase58()}`);
-  if (isAdd) console.log(`  Reason:  ${argv.reason || "(none)"}`);
+  if (isAdd) console.log(`  Reason:  ${argv.reason || "(none)"} [${argv.reasonCode}]`);
+  if (isAdd && argv.expiresAt !== undefined) console.log(`  Expires: ${new Date((argv.expiresAt as number) * 1000).toISOString()}`);
   console.log(`  Tx:      ${sig}`);
 }
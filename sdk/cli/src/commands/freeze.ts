@@ -27,13 +27,21 @@ export async function handler(argv: ArgumentsCamelCase) {
   const [stablecoinPda] = PublicKey.findProgramAddressSync([STABLECOIN_SEED, mintPubkey.toBuffer()], programId);
   const [rolePda] = PublicKey.findProgramAddressSync([ROLE_SEED, stablecoinPda.toBuffer(), authority.publicKey.toBuffer()], programId);
 
+  // Freeze authority: COption<Pubkey> at offset 46 of the mint base layout
+  const mintInfo = await connection.getAccountInfo(mintPubkey);
+  if (!mintInfo) throw new Error(`Mint ${mintPubkey.toBase58()} not found`);
+  const freezeAuthority = mintInfo.data.readUInt32LE(46) === 1
+    ? new PublicKey(mintInfo.data.subarray(50, 82))
+    : stablecoinPda;
+
   const ix = new TransactionInstruction({
     keys: [
       { pubkey: authority.publicKey, isSigner: true, isWritable: false },
-      { pubkey: stablecoinPda, isSigner: false, isWritable: false },
+      { pubkey: stablecoinPda, isSigner: false, isWritable: true },
       { pubkey: rolePda, isSigner: false, isWritable: false },
       { pubkey: mintPubkey, isSigner: false, isWritable: false },
       { pubkey: new PublicKey(argv.account as string), isSigner: false, isWritable: true },
+      { pubkey: freezeAuthority, isSigner: false, isWritable: false },
       { pubkey: new PublicKey(TOKEN_2022_PROGRAM_ID), isSigner: false, isWritable: false },
     ],
     programId,
@@ -1,7 +1,10 @@
 import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, Transaction, TransactionInstruction, sendAndConfirmTransaction } from "@solana/web3.js";
 import * as crypto from "crypto";
 import type { ArgumentsCamelCase, Argv } from "yargs";
-import { loadKeypair, getConnection, PROGRAM_ID, HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, STABLECOIN_SEED, ROLE_SEED } from "../config";
+import {
+  loadKeypair, getConnection, PROGRAM_ID, HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID,
+  STABLECOIN_SEED, ROLE_SEED, FREEZE_AUTHORITY_SEED, ISSUER_REGISTRY_SEED,
+} from "../config";
 
 function disc(name: string): Buffer {
   return crypto.createHash("sha256").update(`global:${name}`).digest().subarray(0, 8);
@@ -23,7 +26,12 @@ export function builder(yargs: Argv) {
     .option("symbol", { type: "string", demandOption: true, description: "Token symbol (max 10 chars)" })
     .option("uri", { type: "string", default: "", description: "Metadata URI" })
     .option("decimals", { type: "number", default: 6, description: "Token decimals" })
-    .option("sss2", { type: "boolean", default: false, description: "Enable SSS-2 compliance (permanent delegate + transfer hook + frozen accounts)" });
+    .option("sss2", { type: "boolean", default: false, description: "Enable SSS-2 compliance (permanent delegate + transfer hook + frozen accounts)" })
+    .option("separate-freeze-authority", { type: "boolean", default: false, description: "Use a dedicated freeze-authority PDA instead of the stablecoin PDA" })
+    .option("treasury", { type: "boolean", default: false, description: "Create the authority's treasury ATA (thawed) during init" })
+    .option("max-uri-len", { type: "number", default: 0, description: "Room to reserve for the URI, up to 512 bytes (0 = 200)" })
+    .option("display-decimals", { type: "number", description: "Preferred display precision, at most --decimals (default: decimals)" })
+    .option("grant-all-roles", { type: "boolean", default: true, description: "Grant the authority every role (--no-grant-all-roles leaves minter, burner and seizer unassigned)" });
 }
 
 export async function handler(argv: ArgumentsCamelCase) {
@@ -36,6 +44,9 @@ export async function handler(argv: ArgumentsCamelCase) {
   const [stablecoinPda] = PublicKey.findProgramAddressSync([STABLECOIN_SEED, mintKeypair.publicKey.toBuffer()], programId);
   const [authorityRole] = PublicKey.findProgramAddressSync([ROLE_SEED, stablecoinPda.toBuffer(), authority.publicKey.toBuffer()], programId);
 
+  const maxUriLen = Buffer.alloc(2);
+  maxUriLen.writeUInt16LE(argv.maxUriLen as number);
+
   const data = Buffer.concat([
     disc("initialize"),
     str(argv.name as string),
@@ -45,18 +56,43 @@ export async function handler(argv: ArgumentsCamelCase) {
     Buffer.from([isSss2 ? 1 : 0]),
     Buffer.from([isSss2 ? 1 : 0]),
     Buffer.from([isSss2 ? 1 : 0]),
+    Buffer.from([argv.separateFreezeAuthority ? 1 : 0]),
+    Buffer.from([argv.treasury ? 1 : 0]),
+    maxUriLen,
+    argv.displayDecimals === undefined ? Buffer.from([0]) : Buffer.from([1, argv.displayDecimals as number]),
+    Buffer.from([argv.grantAllRoles ? 1 : 0]),
   ]);
 
+  const tokenProgram = new PublicKey(TOKEN_2022_PROGRAM_ID);
+  const ataProgram = new PublicKey(ASSOCIATED_TOKEN_PROGRAM_ID);
+  const [treasuryAta] = PublicKey.findProgramAddressSync(
+    [authority.publicKey.toBuffer(), tokenProgram.toBuffer(), mintKeypair.publicKey.toBuffer()],
+    ataProgram
+  );
+  const [freezeAuthority] = argv.separateFreezeAuthority
+    ? PublicKey.findProgramAddressSync([FREEZE_AUTHORITY_SEED, mintKeypair.publicKey.toBuffer()], programId)
+    : [stablecoinPda];
+  const treasuryKeys = argv.treasury
+    ? [
+        { pubkey: treasuryAta, isSigner: false, isWritable: true },
+        { pubkey: ataProgram, isSigner: false, isWritable: false },
+        { pubkey: freezeAuthority, isSigner: false, isWritable: false },
+      ]
+    : [];
+  const [registry] = PublicKey.findProgramAddressSync([ISSUER_REGISTRY_SEED, authority.publicKey.toBuffer()], programId);
+
   const ix = new TransactionInstruction({
     keys: [
       { pubkey: authority.publicKey, isSigner: true, isWritable: true },
       { pubkey: stablecoinPda, isSigner: false, isWritable: true },
       { pubkey: mintKeypair.publicKey, isSigner: true, isWritable: true },
       { pubkey: authorityRole, isSigner: false, isWritable: true },
+      { pubkey: registry, isSigner: false, isWritable: true },
       { pubkey: new PublicKey(HOOK_PROGRAM_ID), isSigner: false, isWritable: false },
-      { pubkey: new PublicKey(TOKEN_2022_PROGRAM_ID), isSigner: false, isWritable: false },
+      { pubkey: tokenProgram, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      ...treasuryKeys,
     ],
     programId,
     data,
@@ -70,5 +106,6 @@ export async function handler(argv: ArgumentsCamelCase) {
   console.log(`  Mint:       ${mintKeypair.publicKey.toBase58()}`);
   console.log(`  Stablecoin: ${stablecoinPda.toBase58()}`);
   console.log(`  Authority:  ${authority.publicKey.toBase58()}`);
+  if (argv.treasury) console.log(`  Treasury:   ${treasuryAta.toBase58()}`);
   console.log(`  Tx:         ${sig}`);
 }
@@ -14,7 +14,8 @@ export function builder(yargs: Argv) {
   return yargs
     .option("mint", { alias: "m", type: "string", demandOption: true, description: "Mint address" })
     .option("to", { type: "string", demandOption: true, description: "Recipient token account" })
-    .option("amount", { type: "string", demandOption: true, description: "Amount (base units)" });
+    .option("amount", { type: "string", demandOption: true, description: "Amount (base units)" })
+    .option("reference", { type: "string", description: "Client reference echoed in TokensMinted (32 hex chars)" });
 }
 
 export async function handler(argv: ArgumentsCamelCase) {
@@ -29,7 +30,10 @@ export async function handler(argv: ArgumentsCamelCase) {
 
   const amountBuf = Buffer.alloc(8);
   amountBuf.writeBigUInt64LE(BigInt(argv.amount as string));
-  const data = Buffer.concat([disc("mint_tokens"), amountBuf]);
+  const reference = argv.reference ? Buffer.from(argv.reference as string, "hex") : undefined;
+  if (reference && reference.length !== 16) throw new Error("--reference must be 16 bytes (32 hex chars)");
+  const referenceBuf = reference ? Buffer.concat([Buffer.from([1]), reference]) : Buffer.from([0]);
+  const data = Buffer.concat([disc("mint_tokens"), amountBuf, referenceBuf]);
 
   const ix = new TransactionInstruction({
     keys: [
@@ -14,7 +14,8 @@ export function builder(yargs: Argv) {
   return yargs
     .option("mint", { alias: "m", type: "string", demandOption: true, description: "Mint address" })
     .option("minter", { type: "string", demandOption: true, description: "Minter public key" })
-    .option("quota", { type: "string", demandOption: true, description: "Minting quota (base units)" });
+    .option("quota", { type: "string", demandOption: true, description: "Minting quota (base units)" })
+    .option("epoch", { type: "number", description: "Quota reset period in seconds (0 = lifetime quota, min 60)" });
 }
 
 export async function handler(argv: ArgumentsCamelCase) {
@@ -29,12 +30,18 @@ export async function handler(argv: ArgumentsCamelCase) {
 
   const quotaBuf = Buffer.alloc(8);
   quotaBuf.writeBigUInt64LE(BigInt(argv.quota as string));
-  const data = Buffer.concat([disc("update_minter"), quotaBuf]);
+  let epochBuf = Buffer.from([0]);
+  if (argv.epoch !== undefined) {
+    epochBuf = Buffer.alloc(9);
+    epochBuf.writeUInt8(1, 0);
+    epochBuf.writeBigInt64LE(BigInt(argv.epoch as number), 1);
+  }
+  const data = Buffer.concat([disc("update_minter"), quotaBuf, epochBuf]);
 
   const ix = new TransactionInstruction({
     keys: [
       { pubkey: authority.publicKey, isSigner: true, isWritable: true },
-      { pubkey: stablecoinPda, isSigner: false, isWritable: false },
+      { pubkey: stablecoinPda, isSigner: false, isWritable: true },
       { pubkey: minterInfoPda, isSigner: false, isWritable: true },
       { pubkey: minterPubkey, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -49,5 +56,6 @@ export async function handler(argv: ArgumentsCamelCase) {
   console.log(`\nMinter quota updated!`);
   console.log(`  Minter: ${minterPubkey.toBase58()}`);
   console.log(`  Quota:  ${argv.quota}`);
+  if (argv.epoch !== undefined) console.log(`  Epoch:  ${argv.epoch}s`);
   console.log(`  Tx:     ${sig}`);
 }
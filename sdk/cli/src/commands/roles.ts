@@ -18,7 +18,9 @@ export function builder(yargs: Argv) {
     .option("burner", { type: "boolean", default: false })
     .option("pauser", { type: "boolean", default: false })
     .option("blacklister", { type: "boolean", default: false })
-    .option("seizer", { type: "boolean", default: false });
+    .option("seizer", { type: "boolean", default: false })
+    .option("blacklist-namespace", { type: "number", default: 0, description: "Blacklist team namespace (0-255)" })
+    .option("metadata-updater", { type: "boolean", default: false, description: "May change the token name and URI" });
 }
 
 export async function handler(argv: ArgumentsCamelCase) {
@@ -39,13 +41,15 @@ export async function handler(argv: ArgumentsCamelCase) {
       argv.pauser ? 1 : 0,
       argv.blacklister ? 1 : 0,
       argv.seizer ? 1 : 0,
+      argv.blacklistNamespace as number,
+      argv.metadataUpdater ? 1 : 0,
     ]),
   ]);
 
   const ix = new TransactionInstruction({
     keys: [
       { pubkey: authority.publicKey, isSigner: true, isWritable: true },
-      { pubkey: stablecoinPda, isSigner: false, isWritable: false },
+      { pubkey: stablecoinPda, isSigner: false, isWritable: true },
       { pubkey: rolePda, isSigner: false, isWritable: true },
       { pubkey: holderPubkey, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -59,6 +63,7 @@ export async function handler(argv: ArgumentsCamelCase) {
 
   console.log(`\nRoles updated for ${holderPubkey.toBase58()}`);
   console.log(`  Minter: ${!!argv.minter}, Burner: ${!!argv.burner}, Pauser: ${!!argv.pauser}`);
-  console.log(`  Blacklister: ${!!argv.blacklister}, Seizer: ${!!argv.seizer}`);
+  console.log(`  Blacklister: ${!!argv.blacklister} (namespace ${argv.blacklistNamespace}), Seizer: ${!!argv.seizer}`);
+  console.log(`  Metadata updater: ${!!argv.metadataUpdater}`);
   console.log(`  Tx: ${sig}`);
 }
@@ -1,7 +1,7 @@
 import { PublicKey, Transaction, TransactionInstruction, sendAndConfirmTransaction } from "@solana/web3.js";
 import * as crypto from "crypto";
 import type { ArgumentsCamelCase, Argv } from "yargs";
-import { loadKeypair, getConnection, PROGRAM_ID, TOKEN_2022_PROGRAM_ID, STABLECOIN_SEED, ROLE_SEED } from "../config";
+import { loadKeypair, getConnection, PROGRAM_ID, TOKEN_2022_PROGRAM_ID, STABLECOIN_SEED, ROLE_SEED, BLACKLIST_SEED } from "../config";
 
 function disc(name: string): Buffer {
   return crypto.createHash("sha256").update(`global:${name}`).digest().subarray(0, 8);
@@ -14,7 +14,8 @@ export function builder(yargs: Argv) {
   return yargs
     .option("mint", { alias: "m", type: "string", demandOption: true, description: "Mint address" })
     .option("source", { type: "string", demandOption: true, description: "Source token account" })
-    .option("destination", { type: "string", demandOption: true, description: "Destination token account" });
+    .option("destination", { type: "string", demandOption: true, description: "Destination token account" })
+    .option("amount", { type: "string", description: "Amount to seize in base units (default: whole balance)" });
 }
 
 export async function handler(argv: ArgumentsCamelCase) {
@@ -26,18 +27,36 @@ export async function handler(argv: ArgumentsCamelCase) {
   const [stablecoinPda] = PublicKey.findProgramAddressSync([STABLECOIN_SEED, mintPubkey.toBuffer()], programId);
   const [rolePda] = PublicKey.findProgramAddressSync([ROLE_SEED, stablecoinPda.toBuffer(), seizer.publicKey.toBuffer()], programId);
 
+  // The program checks the source owner's blacklist entry (token account owner at offset 32)
+  const sourcePubkey = new PublicKey(argv.source as string);
+  const sourceInfo = await connection.getAccountInfo(sourcePubkey);
+  if (!sourceInfo) throw new Error(`Token account ${sourcePubkey.toBase58()} not found`);
+  const sourceOwner = new PublicKey(sourceInfo.data.subarray(32, 64));
+  const [sourceEntryPda] = PublicKey.findProgramAddressSync(
+    [BLACKLIST_SEED, stablecoinPda.toBuffer(), sourceOwner.toBuffer()],
+    programId
+  );
+
+  let data = disc("seize");
+  if (argv.amount !== undefined) {
+    const amountBuf = Buffer.alloc(8);
+    amountBuf.writeBigUInt64LE(BigInt(argv.amount as string));
+    data = Buffer.concat([disc("seize_amount"), amountBuf]);
+  }
+
   const ix = new TransactionInstruction({
     keys: [
       { pubkey: seizer.publicKey, isSigner: true, isWritable: false },
-      { pubkey: stablecoinPda, isSigner: false, isWritable: false },
+      { pubkey: stablecoinPda, isSigner: false, isWritable: true },
       { pubkey: rolePda, isSigner: false, isWritable: false },
       { pubkey: mintPubkey, isSigner: false, isWritable: false },
-      { pubkey: new PublicKey(argv.source as string), isSigner: false, isWritable: true },
+      { pubkey: sourcePubkey, isSigner: false, isWritable: true },
+      { pubkey: sourceEntryPda, isSigner: false, isWritable: false },
       { pubkey: new PublicKey(argv.destination as string), isSigner: false, isWritable: true },
       { pubkey: new PublicKey(TOKEN_2022_PROGRAM_ID), isSigner: false, isWritable: false },
     ],
     programId,
-    data: disc("seize"),
+    data,
   });
 
   const tx = new Transaction().add(ix);
@@ -12,11 +12,14 @@ const DEFAULT_KEYPAIR_PATH = path.join(
 export const PROGRAM_ID = "2D8s3bH6vD3LG7wqzvpSvYFysYoSK4wwggHCptaKFJJQ";
 export const HOOK_PROGRAM_ID = "F2of7agMFET8v3verXe3e6Hmfd71t833RjPxEjs5wRdd";
 export const TOKEN_2022_PROGRAM_ID = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+export const ASSOCIATED_TOKEN_PROGRAM_ID = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
 
 export const STABLECOIN_SEED = Buffer.from("stablecoin");
 export const ROLE_SEED = Buffer.from("role");
 export const MINTER_SEED = Buffer.from("minter");
 export const BLACKLIST_SEED = Buffer.from("blacklist");
+export const FREEZE_AUTHORITY_SEED = Buffer.from("freeze_auth");
+export const ISSUER_REGISTRY_SEED = Buffer.from("issuer_registry");
 
 export function loadKeypair(keypairPath?: string): Keypair {
   const resolved = keypairPath || DEFAULT_KEYPAIR_PATH;
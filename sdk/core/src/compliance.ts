@@ -1,5 +1,6 @@
 import {
   Connection,
+  GetProgramAccountsFilter,
   Keypair,
   PublicKey,
   SystemProgram,
@@ -7,14 +8,40 @@ import {
   TransactionInstruction,
   sendAndConfirmTransaction,
 } from "@solana/web3.js";
+import {
+  addExtraAccountMetasForExecute,
+  getAccount,
+  getMint,
+  getTransferHook,
+} from "@solana/spl-token";
 import * as crypto from "crypto";
-import { SSS_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from "./types";
+import { BLACKLIST_REASON_CODES, SSS_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from "./types";
 import { findRolePDA, findBlacklistPDA } from "./pda";
 
 function anchorDisc(name: string): Buffer {
   return crypto.createHash("sha256").update(`global:${name}`).digest().subarray(0, 8);
 }
 
+/** Byte offset of `BlacklistEntry.stablecoin` (after the 8-byte discriminator). */
+export const BLACKLIST_ENTRY_STABLECOIN_OFFSET = 8;
+/** Byte offset of `BlacklistEntry.address`. */
+export const BLACKLIST_ENTRY_ADDRESS_OFFSET = BLACKLIST_ENTRY_STABLECOIN_OFFSET + 32;
+
+/**
+ * `getProgramAccounts` filter matching every blacklist entry of `stablecoin`:
+ * the account discriminator followed by the stablecoin key, at offset 0.
+ */
+export function blacklistEntryFilter(stablecoin: PublicKey): GetProgramAccountsFilter {
+  const disc = crypto.createHash("sha256").update("account:BlacklistEntry").digest().subarray(0, 8);
+  return {
+    memcmp: {
+      offset: 0,
+      bytes: Buffer.concat([disc, stablecoin.toBuffer()]).toString("base64"),
+      encoding: "base64",
+    },
+  };
+}
+
 /**
  * SSS-2 compliance operations: blacklist management and asset seizure.
  */
@@ -26,27 +53,38 @@ export class ComplianceModule {
   ) {}
 
   /**
-   * Add an address to the blacklist (blacklister role required).
+   * Add an address to the blacklist (blacklister role required). With
+   * `expiresAt` (unix seconds) the entry stops applying after that time.
+   * `reasonCode` (one of `BLACKLIST_REASON_CODES`) categorizes the entry.
    */
   async addToBlacklist(
     blacklister: Keypair,
     address: PublicKey,
-    reason: string
+    reason: string,
+    expiresAt?: bigint,
+    reasonCode: number = BLACKLIST_REASON_CODES.other
   ): Promise<string> {
     const [role] = findRolePDA(this.stablecoinPDA, blacklister.publicKey);
     const [blacklistEntry] = findBlacklistPDA(this.stablecoinPDA, address);
 
     const reasonBytes = Buffer.from(reason, "utf-8");
+    const expiry = Buffer.alloc(expiresAt === undefined ? 1 : 9);
+    if (expiresAt !== undefined) {
+      expiry[0] = 1;
+      expiry.writeBigInt64LE(expiresAt, 1);
+    }
     const data = Buffer.concat([
       anchorDisc("add_to_blacklist"),
       Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
       reasonBytes,
+      Buffer.from([reasonCode]),
+      expiry,
     ]);
 
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: blacklister.publicKey, isSigner: true, isWritable: true },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: role, isSigner: false, isWritable: false },
         { pubkey: blacklistEntry, isSigner: false, isWritable: true },
         { pubkey: address, isSigner: false, isWritable: false },
@@ -60,6 +98,51 @@ export class ComplianceModule {
     return sendAndConfirmTransaction(this.connection, tx, [blacklister]);
   }
 
+  /**
+   * Blacklist an address and freeze its token account atomically (blacklister
+   * and pauser roles, or the master authority). `freezeAuthority` defaults to
+   * the stablecoin PDA.
+   */
+  async lockdown(
+    responder: Keypair,
+    address: PublicKey,
+    targetTokenAccount: PublicKey,
+    reason: string,
+    freezeAuthority: PublicKey = this.stablecoinPDA,
+    reasonCode: number = BLACKLIST_REASON_CODES.other
+  ): Promise<string> {
+    const [role] = findRolePDA(this.stablecoinPDA, responder.publicKey);
+    const [blacklistEntry] = findBlacklistPDA(this.stablecoinPDA, address);
+
+    const reasonBytes = Buffer.from(reason, "utf-8");
+    const data = Buffer.concat([
+      anchorDisc("lockdown"),
+      Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
+      reasonBytes,
+      Buffer.from([reasonCode]),
+    ]);
+
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: responder.publicKey, isSigner: true, isWritable: true },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+        { pubkey: role, isSigner: false, isWritable: false },
+        { pubkey: blacklistEntry, isSigner: false, isWritable: true },
+        { pubkey: address, isSigner: false, isWritable: false },
+        { pubkey: this.mint, isSigner: false, isWritable: false },
+        { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: freezeAuthority, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data,
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [responder]);
+  }
+
   /**
    * Remove an address from the blacklist.
    */
@@ -73,7 +156,7 @@ export class ComplianceModule {
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: blacklister.publicKey, isSigner: true, isWritable: true },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: role, isSigner: false, isWritable: false },
         { pubkey: blacklistEntry, isSigner: false, isWritable: true },
         { pubkey: address, isSigner: false, isWritable: false },
@@ -87,30 +170,87 @@ export class ComplianceModule {
   }
 
   /**
-   * Seize all tokens from a blacklisted account (seizer role required).
-   * Uses the permanent delegate authority on the mint.
+   * Remove an address from the blacklist if it is on it. Unlike
+   * `removeFromBlacklist` this does not fail for an address that is not
+   * blacklisted, so it is safe to call in bulk.
+   */
+  async tryRemoveFromBlacklist(
+    blacklister: Keypair,
+    address: PublicKey
+  ): Promise<string> {
+    const [role] = findRolePDA(this.stablecoinPDA, blacklister.publicKey);
+    const [blacklistEntry] = findBlacklistPDA(this.stablecoinPDA, address);
+
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: blacklister.publicKey, isSigner: true, isWritable: true },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+        { pubkey: role, isSigner: false, isWritable: false },
+        { pubkey: blacklistEntry, isSigner: false, isWritable: true },
+        { pubkey: address, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("try_remove_from_blacklist"),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [blacklister]);
+  }
+
+  /**
+   * Seize tokens from a blacklisted account (seizer role required): the
+   * whole balance, or only `amount` when given. Uses the permanent delegate
+   * authority on the mint. Pass `feeTreasury` (the configured seizure fee
+   * treasury) when a seizure fee is set. The source owner's blacklist entry
+   * is always passed, so a lapsed temporary entry blocks the seizure.
    */
   async seize(
     seizer: Keypair,
     sourceTokenAccount: PublicKey,
-    destinationTokenAccount: PublicKey
+    destinationTokenAccount: PublicKey,
+    feeTreasury?: PublicKey,
+    amount?: bigint
   ): Promise<string> {
     const [role] = findRolePDA(this.stablecoinPDA, seizer.publicKey);
+    const source = await getAccount(this.connection, sourceTokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const [sourceEntry] = findBlacklistPDA(this.stablecoinPDA, source.owner);
+    let data = anchorDisc("seize");
+    if (amount !== undefined) {
+      const amountBuf = Buffer.alloc(8);
+      amountBuf.writeBigUInt64LE(amount);
+      data = Buffer.concat([anchorDisc("seize_amount"), amountBuf]);
+    }
 
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: seizer.publicKey, isSigner: true, isWritable: false },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: role, isSigner: false, isWritable: false },
         { pubkey: this.mint, isSigner: false, isWritable: false },
         { pubkey: sourceTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: sourceEntry, isSigner: false, isWritable: false },
         { pubkey: destinationTokenAccount, isSigner: false, isWritable: true },
         { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...(feeTreasury ? [{ pubkey: feeTreasury, isSigner: false, isWritable: true }] : []),
       ],
       programId: SSS_TOKEN_PROGRAM_ID,
-      data: anchorDisc("seize"),
+      data,
     });
 
+    // On transfer-hook mints every delegate transfer needs the hook's extra
+    // accounts, resolved once per destination (fee treasury, then treasury)
+    const mintInfo = await getMint(this.connection, this.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const hook = getTransferHook(mintInfo);
+    if (hook && !hook.programId.equals(PublicKey.default)) {
+      for (const destination of [feeTreasury, destinationTokenAccount]) {
+        if (!destination) continue;
+        await addExtraAccountMetasForExecute(
+          this.connection, ix, hook.programId, sourceTokenAccount, this.mint, destination,
+          this.stablecoinPDA, amount ?? source.amount, "confirmed"
+        );
+      }
+    }
+
     const tx = new Transaction().add(ix);
     return sendAndConfirmTransaction(this.connection, tx, [seizer]);
   }
@@ -123,4 +263,16 @@ export class ComplianceModule {
     const info = await this.connection.getAccountInfo(blacklistPDA);
     return info !== null && info.data.length > 0;
   }
+
+  /**
+   * List every blacklisted address of this stablecoin. Only the `address`
+   * field is fetched, so this stays cheap for large lists.
+   */
+  async listBlacklisted(): Promise<PublicKey[]> {
+    const accounts = await this.connection.getProgramAccounts(SSS_TOKEN_PROGRAM_ID, {
+      filters: [blacklistEntryFilter(this.stablecoinPDA)],
+      dataSlice: { offset: BLACKLIST_ENTRY_ADDRESS_OFFSET, length: 32 },
+    });
+    return accounts.map(({ account }) => new PublicKey(account.data));
+  }
 }
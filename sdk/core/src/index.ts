@@ -3,4 +3,9 @@ export * from "./pda";
 export * from "./presets";
 export { SolanaStablecoin } from "./stablecoin";
 export { RoleManager } from "./roles";
-export { ComplianceModule } from "./compliance";
+export {
+  ComplianceModule,
+  BLACKLIST_ENTRY_STABLECOIN_OFFSET,
+  BLACKLIST_ENTRY_ADDRESS_OFFSET,
+  blacklistEntryFilter,
+} from "./compliance";
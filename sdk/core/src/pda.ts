@@ -5,6 +5,9 @@ const STABLECOIN_SEED = Buffer.from("stablecoin");
 const ROLE_SEED = Buffer.from("role");
 const MINTER_SEED = Buffer.from("minter");
 const BLACKLIST_SEED = Buffer.from("blacklist");
+const FREEZE_AUTHORITY_SEED = Buffer.from("freeze_auth");
+const ISSUER_REGISTRY_SEED = Buffer.from("issuer_registry");
+const MINT_LOCK_SEED = Buffer.from("mint_lock");
 
 export function findStablecoinPDA(mint: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
@@ -42,3 +45,27 @@ export function findBlacklistPDA(
     SSS_TOKEN_PROGRAM_ID
   );
 }
+
+export function findFreezeAuthorityPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [FREEZE_AUTHORITY_SEED, mint.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findIssuerRegistryPDA(authority: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [ISSUER_REGISTRY_SEED, authority.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findMintLockPDA(
+  stablecoin: PublicKey,
+  tokenAccount: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [MINT_LOCK_SEED, stablecoin.toBuffer(), tokenAccount.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
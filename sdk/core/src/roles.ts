@@ -5,6 +5,8 @@ import {
   SystemProgram,
   Transaction,
   TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
   sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import * as crypto from "crypto";
@@ -42,13 +44,15 @@ export class RoleManager {
         roles.isPauser ? 1 : 0,
         roles.isBlacklister ? 1 : 0,
         roles.isSeizer ? 1 : 0,
+        roles.blacklistNamespace ?? 0,
+        roles.isMetadataUpdater ? 1 : 0,
       ]),
     ]);
 
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: authority.publicKey, isSigner: true, isWritable: true },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: rolePDA, isSigner: false, isWritable: true },
         { pubkey: holder, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -62,24 +66,34 @@ export class RoleManager {
   }
 
   /**
-   * Set a minter's quota (authority-only).
+   * Set a minter's quota (authority-only). With `epochDuration` (seconds) the
+   * quota resets every epoch; 0 makes it a lifetime quota, omitted keeps the
+   * current setting.
    */
   async updateMinter(
     authority: Keypair,
     minter: PublicKey,
-    quota: bigint
+    quota: bigint,
+    epochDuration?: bigint
   ): Promise<string> {
     const [minterInfoPDA] = findMinterPDA(this.stablecoinPDA, minter);
 
     const quotaBuf = Buffer.alloc(8);
     quotaBuf.writeBigUInt64LE(quota);
 
-    const data = Buffer.concat([anchorDisc("update_minter"), quotaBuf]);
+    let epochBuf = Buffer.from([0]);
+    if (epochDuration !== undefined) {
+      epochBuf = Buffer.alloc(9);
+      epochBuf.writeUInt8(1, 0);
+      epochBuf.writeBigInt64LE(epochDuration, 1);
+    }
+
+    const data = Buffer.concat([anchorDisc("update_minter"), quotaBuf, epochBuf]);
 
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: authority.publicKey, isSigner: true, isWritable: true },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: minterInfoPDA, isSigner: false, isWritable: true },
         { pubkey: minter, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -91,4 +105,97 @@ export class RoleManager {
     const tx = new Transaction().add(ix);
     return sendAndConfirmTransaction(this.connection, tx, [authority]);
   }
+
+  /**
+   * Set one role (a `ROLE_BITS` value) to `value` for every holder at once
+   * (authority-only), e.g. strip the minter role from all desks during an
+   * incident. Holders must already have a role account; up to 20 per call.
+   */
+  async bulkSetRoleFlag(
+    authority: Keypair,
+    flag: number,
+    value: boolean,
+    holders: PublicKey[]
+  ): Promise<string> {
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: authority.publicKey, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+        ...holders.map((holder) => ({
+          pubkey: findRolePDA(this.stablecoinPDA, holder)[0],
+          isSigner: false,
+          isWritable: true,
+        })),
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: Buffer.concat([anchorDisc("bulk_set_role_flag"), Buffer.from([flag, value ? 1 : 0])]),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [authority]);
+  }
+
+  /**
+   * Revoke a minter (authority-only): clears `isMinter` on their role and
+   * closes their minter PDA, refunding its rent to the authority.
+   */
+  async revokeMinter(authority: Keypair, minter: PublicKey): Promise<string> {
+    const [rolePDA] = findRolePDA(this.stablecoinPDA, minter);
+    const [minterInfoPDA] = findMinterPDA(this.stablecoinPDA, minter);
+
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+        { pubkey: rolePDA, isSigner: false, isWritable: true },
+        { pubkey: minterInfoPDA, isSigner: false, isWritable: true },
+        { pubkey: minter, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("revoke_minter"),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [authority]);
+  }
+
+  /**
+   * Roles a holder can exercise right now, as reported by the program.
+   * Read-only: the instruction is simulated with `payer` as fee payer.
+   */
+  async getEffectiveRoles(holder: PublicKey, payer: PublicKey): Promise<RoleFlags> {
+    const [rolePDA] = findRolePDA(this.stablecoinPDA, holder);
+
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: rolePDA, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("get_effective_roles"),
+    });
+
+    const { blockhash } = await this.connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+      payerKey: payer,
+      recentBlockhash: blockhash,
+      instructions: [ix],
+    }).compileToV0Message();
+    const { value } = await this.connection.simulateTransaction(
+      new VersionedTransaction(message),
+      { sigVerify: false }
+    );
+    if (value.err || !value.returnData) {
+      throw new Error(`get_effective_roles failed: ${JSON.stringify(value.err)}`);
+    }
+
+    const bits = Buffer.from(value.returnData.data[0], "base64")[0];
+    return {
+      isMinter: (bits & (1 << 0)) !== 0,
+      isBurner: (bits & (1 << 1)) !== 0,
+      isPauser: (bits & (1 << 2)) !== 0,
+      isBlacklister: (bits & (1 << 3)) !== 0,
+      isSeizer: (bits & (1 << 4)) !== 0,
+    };
+  }
 }
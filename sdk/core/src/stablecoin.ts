@@ -5,10 +5,17 @@ import {
   SystemProgram,
   Transaction,
   TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
   sendAndConfirmTransaction,
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import * as crypto from "crypto";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getMint,
+} from "@solana/spl-token";
 import {
   SSS_TOKEN_PROGRAM_ID,
   SSS_HOOK_PROGRAM_ID,
@@ -17,7 +24,14 @@ import {
   type StablecoinState,
   type RoleFlags,
 } from "./types";
-import { findStablecoinPDA, findRolePDA, findMinterPDA } from "./pda";
+import {
+  findStablecoinPDA,
+  findRolePDA,
+  findMinterPDA,
+  findFreezeAuthorityPDA,
+  findIssuerRegistryPDA,
+  findMintLockPDA,
+} from "./pda";
 
 function anchorDisc(name: string): Buffer {
   return crypto.createHash("sha256").update(`global:${name}`).digest().subarray(0, 8);
@@ -30,6 +44,12 @@ function serializeString(s: string): Buffer {
   return Buffer.concat([len, bytes]);
 }
 
+function serializeU16(n: number): Buffer {
+  const buf = Buffer.alloc(2);
+  buf.writeUInt16LE(n);
+  return buf;
+}
+
 function serializeU64(n: bigint): Buffer {
   const buf = Buffer.alloc(8);
   buf.writeBigUInt64LE(n);
@@ -67,18 +87,45 @@ export class SolanaStablecoin {
       Buffer.from([config.enablePermanentDelegate ? 1 : 0]),
       Buffer.from([config.enableTransferHook ? 1 : 0]),
       Buffer.from([config.defaultAccountFrozen ? 1 : 0]),
+      Buffer.from([config.separateFreezeAuthority ? 1 : 0]),
+      Buffer.from([config.createTreasury ? 1 : 0]),
+      serializeU16(config.maxUriLen ?? 0),
+      config.displayDecimals === undefined ? Buffer.from([0]) : Buffer.from([1, config.displayDecimals]),
+      Buffer.from([config.initGrantAllRoles === false ? 0 : 1]),
     ]);
 
+    const treasuryKeys = config.createTreasury
+      ? [
+          {
+            pubkey: getAssociatedTokenAddressSync(
+              mintKeypair.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID
+            ),
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          {
+            pubkey: config.separateFreezeAuthority
+              ? findFreezeAuthorityPDA(mintKeypair.publicKey)[0]
+              : stablecoinPDA,
+            isSigner: false,
+            isWritable: false,
+          },
+        ]
+      : [];
+
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: authority.publicKey, isSigner: true, isWritable: true },
         { pubkey: stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: mintKeypair.publicKey, isSigner: true, isWritable: true },
         { pubkey: authorityRole, isSigner: false, isWritable: true },
+        { pubkey: findIssuerRegistryPDA(authority.publicKey)[0], isSigner: false, isWritable: true },
         { pubkey: SSS_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        ...treasuryKeys,
       ],
       programId: SSS_TOKEN_PROGRAM_ID,
       data,
@@ -97,6 +144,25 @@ export class SolanaStablecoin {
     };
   }
 
+  /**
+   * Create the authority's issuer registry, after which `create` rejects
+   * symbols the authority has already used.
+   */
+  static async initIssuerRegistry(connection: Connection, authority: Keypair): Promise<string> {
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+        { pubkey: findIssuerRegistryPDA(authority.publicKey)[0], isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("init_issuer_registry"),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(connection, tx, [authority]);
+  }
+
   /**
    * Load an existing stablecoin from its mint address.
    */
@@ -106,17 +172,25 @@ export class SolanaStablecoin {
   }
 
   /**
-   * Mint tokens to a recipient's token account.
+   * Mint tokens to a recipient's token account. Pass `mintLock` when the
+   * stablecoin has a `mint_lock_duration`, so the recipient's lock is recorded.
+   * An optional 16-byte `reference` is echoed in the `TokensMinted` event.
+   * Pass the configured `reserveOracle` when a reserve ratio is enforced.
    */
   async mintTokens(
     minter: Keypair,
     recipientTokenAccount: PublicKey,
-    amount: bigint
+    amount: bigint,
+    mintLock = false,
+    reference?: Uint8Array,
+    reserveOracle?: PublicKey
   ): Promise<string> {
     const [role] = findRolePDA(this.stablecoinPDA, minter.publicKey);
     const [minterInfo] = findMinterPDA(this.stablecoinPDA, minter.publicKey);
 
-    const data = Buffer.concat([anchorDisc("mint_tokens"), serializeU64(amount)]);
+    if (reference && reference.length !== 16) throw new Error("reference must be 16 bytes");
+    const referenceBuf = reference ? Buffer.concat([Buffer.from([1]), Buffer.from(reference)]) : Buffer.from([0]);
+    const data = Buffer.concat([anchorDisc("mint_tokens"), serializeU64(amount), referenceBuf]);
 
     const ix = new TransactionInstruction({
       keys: [
@@ -127,6 +201,13 @@ export class SolanaStablecoin {
         { pubkey: this.mint, isSigner: false, isWritable: true },
         { pubkey: recipientTokenAccount, isSigner: false, isWritable: true },
         { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...(mintLock
+          ? [
+              { pubkey: findMintLockPDA(this.stablecoinPDA, recipientTokenAccount)[0], isSigner: false, isWritable: true },
+              { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            ]
+          : []),
+        ...(reserveOracle ? [{ pubkey: reserveOracle, isSigner: false, isWritable: false }] : []),
       ],
       programId: SSS_TOKEN_PROGRAM_ID,
       data,
@@ -165,22 +246,79 @@ export class SolanaStablecoin {
     return sendAndConfirmTransaction(this.connection, tx, [burner]);
   }
 
+  /**
+   * Resolve the mint's freeze authority — the stablecoin PDA, or the
+   * separate ["freeze_auth", mint] PDA if one was configured at init.
+   */
+  async getFreezeAuthority(): Promise<PublicKey> {
+    const mint = await getMint(this.connection, this.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    return mint.freezeAuthority ?? this.stablecoinPDA;
+  }
+
   /**
    * Freeze a token account (pauser role required).
    */
+  /**
+   * Whether the program's PDAs still hold each mint authority. Read-only:
+   * the instruction is simulated with `payer` as fee payer.
+   */
+  async checkAuthorities(payer: PublicKey): Promise<{
+    mint: boolean;
+    freeze: boolean;
+    close: boolean;
+    permanentDelegate: boolean;
+  }> {
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.mint, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("check_authorities"),
+    });
+
+    const { blockhash } = await this.connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+      payerKey: payer,
+      recentBlockhash: blockhash,
+      instructions: [ix],
+    }).compileToV0Message();
+    const { value } = await this.connection.simulateTransaction(
+      new VersionedTransaction(message),
+      { sigVerify: false }
+    );
+    if (value.err) {
+      throw new Error(`check_authorities failed: ${JSON.stringify(value.err)}`);
+    }
+
+    // Bitfield of diverged authorities; trailing zero bytes are trimmed, so
+    // missing return data means nothing diverged.
+    const diverged = value.returnData
+      ? Buffer.from(value.returnData.data[0], "base64")[0] ?? 0
+      : 0;
+    return {
+      mint: (diverged & (1 << 0)) === 0,
+      freeze: (diverged & (1 << 1)) === 0,
+      close: (diverged & (1 << 2)) === 0,
+      permanentDelegate: (diverged & (1 << 3)) === 0,
+    };
+  }
+
   async freezeAccount(
     authority: Keypair,
     targetTokenAccount: PublicKey
   ): Promise<string> {
     const [role] = findRolePDA(this.stablecoinPDA, authority.publicKey);
+    const freezeAuthority = await this.getFreezeAuthority();
 
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: authority.publicKey, isSigner: true, isWritable: false },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: role, isSigner: false, isWritable: false },
         { pubkey: this.mint, isSigner: false, isWritable: false },
         { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: freezeAuthority, isSigner: false, isWritable: false },
         { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
       ],
       programId: SSS_TOKEN_PROGRAM_ID,
@@ -199,14 +337,16 @@ export class SolanaStablecoin {
     targetTokenAccount: PublicKey
   ): Promise<string> {
     const [role] = findRolePDA(this.stablecoinPDA, authority.publicKey);
+    const freezeAuthority = await this.getFreezeAuthority();
 
     const ix = new TransactionInstruction({
       keys: [
         { pubkey: authority.publicKey, isSigner: true, isWritable: false },
-        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
         { pubkey: role, isSigner: false, isWritable: false },
         { pubkey: this.mint, isSigner: false, isWritable: false },
         { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: freezeAuthority, isSigner: false, isWritable: false },
         { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
       ],
       programId: SSS_TOKEN_PROGRAM_ID,
@@ -258,7 +398,29 @@ export class SolanaStablecoin {
   }
 
   /**
-   * Transfer master authority to a new address.
+   * Change the token's name and URI. The signer needs the metadata updater
+   * role; the symbol cannot be changed.
+   */
+  async updateMetadata(updater: Keypair, name: string, uri: string): Promise<string> {
+    const [role] = findRolePDA(this.stablecoinPDA, updater.publicKey);
+
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: updater.publicKey, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+        { pubkey: role, isSigner: false, isWritable: false },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: Buffer.concat([anchorDisc("update_metadata"), serializeString(name), serializeString(uri)]),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [updater]);
+  }
+
+  /**
+   * Propose a new master authority. The transfer completes when the new
+   * authority calls `acceptAuthority` after the configured cooldown.
    */
   async transferAuthority(
     authority: Keypair,
@@ -277,4 +439,56 @@ export class SolanaStablecoin {
     const tx = new Transaction().add(ix);
     return sendAndConfirmTransaction(this.connection, tx, [authority]);
   }
+
+  /**
+   * Accept a pending authority transfer as the proposed authority.
+   */
+  async acceptAuthority(newAuthority: Keypair): Promise<string> {
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: newAuthority.publicKey, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("accept_authority"),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [newAuthority]);
+  }
+
+  /**
+   * Withdraw a pending authority transfer (current authority only).
+   */
+  async cancelAuthorityTransfer(authority: Keypair): Promise<string> {
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: authority.publicKey, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("cancel_authority_transfer"),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [authority]);
+  }
+
+  /**
+   * Claim the authority role as the recovery authority, once the current
+   * authority has been inactive for the configured recovery period.
+   */
+  async claimRecovery(recoveryAuthority: Keypair): Promise<string> {
+    const ix = new TransactionInstruction({
+      keys: [
+        { pubkey: recoveryAuthority.publicKey, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoinPDA, isSigner: false, isWritable: true },
+      ],
+      programId: SSS_TOKEN_PROGRAM_ID,
+      data: anchorDisc("claim_recovery"),
+    });
+
+    const tx = new Transaction().add(ix);
+    return sendAndConfirmTransaction(this.connection, tx, [recoveryAuthority]);
+  }
 }
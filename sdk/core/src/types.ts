@@ -36,8 +36,30 @@ export interface RoleFlags {
   isPauser: boolean;
   isBlacklister: boolean;
   isSeizer: boolean;
+  /** Blacklist team namespace the holder adds and removes entries under (default 0) */
+  blacklistNamespace?: number;
+  /** May rename the token and change its URI via `update_metadata` */
+  isMetadataUpdater?: boolean;
 }
 
+/** Role bits, as used by `getEffectiveRoles` and `bulkSetRoleFlag` */
+export const ROLE_BITS = {
+  minter: 1 << 0,
+  burner: 1 << 1,
+  pauser: 1 << 2,
+  blacklister: 1 << 3,
+  seizer: 1 << 4,
+  metadataUpdater: 1 << 5,
+} as const;
+
+/** Blacklist entry categories (`BlacklistReasonCode`) for compliance reporting */
+export const BLACKLIST_REASON_CODES = {
+  other: 0,
+  sanctions: 1,
+  fraud: 2,
+  courtOrder: 3,
+} as const;
+
 export interface RoleAccount {
   stablecoin: PublicKey;
   holder: PublicKey;
@@ -50,6 +72,9 @@ export interface MinterInfo {
   minter: PublicKey;
   quota: bigint;
   mintedAmount: bigint;
+  epochDuration: bigint;
+  epochStart: bigint;
+  mintedThisEpoch: bigint;
   bump: number;
 }
 
@@ -60,6 +85,7 @@ export interface BlacklistEntry {
   blacklistedAt: bigint;
   blacklistedBy: PublicKey;
   bump: number;
+  reasonCode: number;
 }
 
 // ── Initialization Config ───────────────────────────────────────────
@@ -72,21 +98,34 @@ export interface StablecoinConfig {
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
+  /** Use a dedicated ["freeze_auth", mint] PDA as freeze authority */
+  separateFreezeAuthority?: boolean;
+  /** Create (and thaw, if frozen by default) the authority's treasury ATA at init */
+  createTreasury?: boolean;
+  /** Room to reserve for the URI, up to 512 bytes (default 200) */
+  maxUriLen?: number;
+  /** Preferred display precision for amounts, at most `decimals` (default `decimals`) */
+  displayDecimals?: number;
+  /** Grant the authority every role; when false only pauser and blacklister (default true) */
+  initGrantAllRoles?: boolean;
 }
 
 // ── Event Types ─────────────────────────────────────────────────────
 
 export type StablecoinEvent =
-  | { type: "StablecoinInitialized"; stablecoin: PublicKey; mint: PublicKey; authority: PublicKey; name: string; symbol: string; isSss2: boolean; timestamp: bigint }
-  | { type: "TokensMinted"; stablecoin: PublicKey; minter: PublicKey; recipient: PublicKey; amount: bigint; totalMinted: bigint; timestamp: bigint }
+  | { type: "StablecoinInitialized"; stablecoin: PublicKey; mint: PublicKey; authority: PublicKey; name: string; symbol: string; isSss2: boolean; decimals: number; displayDecimals: number; timestamp: bigint }
+  | { type: "TokensMinted"; stablecoin: PublicKey; minter: PublicKey; recipient: PublicKey; amount: bigint; totalMinted: bigint; timestamp: bigint; reference: Uint8Array | null }
   | { type: "TokensBurned"; stablecoin: PublicKey; burner: PublicKey; amount: bigint; totalBurned: bigint; timestamp: bigint }
   | { type: "AccountFrozen"; stablecoin: PublicKey; account: PublicKey; frozenBy: PublicKey; timestamp: bigint }
   | { type: "AccountThawed"; stablecoin: PublicKey; account: PublicKey; thawedBy: PublicKey; timestamp: bigint }
   | { type: "StablecoinPaused"; stablecoin: PublicKey; pausedBy: PublicKey; timestamp: bigint }
   | { type: "StablecoinUnpaused"; stablecoin: PublicKey; unpausedBy: PublicKey; timestamp: bigint }
   | { type: "RolesUpdated"; stablecoin: PublicKey; holder: PublicKey; roles: RoleFlags; updatedBy: PublicKey; timestamp: bigint }
-  | { type: "MinterUpdated"; stablecoin: PublicKey; minter: PublicKey; newQuota: bigint; updatedBy: PublicKey; timestamp: bigint }
+  | { type: "MinterUpdated"; stablecoin: PublicKey; minter: PublicKey; newQuota: bigint; epochDuration: bigint; updatedBy: PublicKey; timestamp: bigint }
+  | { type: "MinterQuotaExhausted"; stablecoin: PublicKey; minter: PublicKey; quota: bigint; mintedAmount: bigint; timestamp: bigint }
+  | { type: "AuthorityTransferProposed"; stablecoin: PublicKey; authority: PublicKey; pendingAuthority: PublicKey; acceptAfter: bigint; timestamp: bigint }
   | { type: "AuthorityTransferred"; stablecoin: PublicKey; previousAuthority: PublicKey; newAuthority: PublicKey; timestamp: bigint }
-  | { type: "AddedToBlacklist"; stablecoin: PublicKey; address: PublicKey; reason: string; blacklistedBy: PublicKey; timestamp: bigint }
+  | { type: "AddedToBlacklist"; stablecoin: PublicKey; address: PublicKey; reason: string; blacklistedBy: PublicKey; timestamp: bigint; expiresAt: bigint | null; reasonCode: number }
   | { type: "RemovedFromBlacklist"; stablecoin: PublicKey; address: PublicKey; removedBy: PublicKey; timestamp: bigint }
-  | { type: "TokensSeized"; stablecoin: PublicKey; from: PublicKey; to: PublicKey; amount: bigint; seizedBy: PublicKey; timestamp: bigint };
+  | { type: "BlacklistOwnerReassigned"; stablecoin: PublicKey; address: PublicKey; previousOwner: PublicKey; newOwner: PublicKey; reassignedBy: PublicKey; timestamp: bigint }
+  | { type: "TokensSeized"; stablecoin: PublicKey; from: PublicKey; to: PublicKey; amount: bigint; fee: bigint; seizedBy: PublicKey; timestamp: bigint };
@@ -1,5 +1,7 @@
 import {
+  AccountMeta,
   Connection,
+  GetProgramAccountsFilter,
   Keypair,
   PublicKey,
   SystemProgram,
@@ -50,6 +52,43 @@ export function findMinterPDA(
   );
 }
 
+export function findMinterStatsPDA(
+  stablecoin: PublicKey,
+  minter: PublicKey,
+  period: bigint
+): [PublicKey, number] {
+  const periodBuf = Buffer.alloc(8);
+  periodBuf.writeBigUInt64LE(period);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("minter_stats"), stablecoin.toBuffer(), minter.toBuffer(), periodBuf],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findFreezeAuthorityPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("freeze_auth"), mint.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findIssuerRegistryPDA(authority: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("issuer_registry"), authority.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findMintLockPDA(
+  stablecoin: PublicKey,
+  tokenAccount: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_lock"), stablecoin.toBuffer(), tokenAccount.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
 export function findBlacklistPDA(
   stablecoin: PublicKey,
   address: PublicKey
@@ -60,6 +99,25 @@ export function findBlacklistPDA(
   );
 }
 
+/** memcmp filter (offset 0) matching every BlacklistEntry of `stablecoin`. */
+export function blacklistEntryFilter(stablecoin: PublicKey): GetProgramAccountsFilter {
+  const disc = crypto.createHash("sha256").update("account:BlacklistEntry").digest().subarray(0, 8);
+  return {
+    memcmp: {
+      offset: 0,
+      bytes: Buffer.concat([disc, stablecoin.toBuffer()]).toString("base64"),
+      encoding: "base64",
+    },
+  };
+}
+
+export function findExtraAccountMetaListPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), mint.toBuffer()],
+    SSS_HOOK_PROGRAM_ID
+  );
+}
+
 // ── Anchor Discriminator ───────────────────────────────────────────
 
 export function anchorDiscriminator(name: string): Buffer {
@@ -77,6 +135,11 @@ export interface InitializeParams {
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
+  separateFreezeAuthority?: boolean;
+  createTreasury?: boolean;
+  maxUriLen?: number;
+  displayDecimals?: number;
+  initGrantAllRoles?: boolean;
 }
 
 export function serializeInitializeParams(params: InitializeParams): Buffer {
@@ -102,6 +165,16 @@ export function serializeInitializeParams(params: InitializeParams): Buffer {
     Buffer.from([params.enableTransferHook ? 1 : 0]),
     // default_account_frozen: bool
     Buffer.from([params.defaultAccountFrozen ? 1 : 0]),
+    // separate_freeze_authority: bool
+    Buffer.from([params.separateFreezeAuthority ? 1 : 0]),
+    // create_treasury: bool
+    Buffer.from([params.createTreasury ? 1 : 0]),
+    // max_uri_len: u16 (0 = default)
+    Buffer.from(new Uint16Array([params.maxUriLen ?? 0]).buffer),
+    // display_decimals: Option<u8> (None = decimals)
+    params.displayDecimals === undefined ? Buffer.from([0]) : Buffer.from([1, params.displayDecimals]),
+    // init_grant_all_roles: bool (default true)
+    Buffer.from([params.initGrantAllRoles === false ? 0 : 1]),
   ];
   return Buffer.concat(buffers);
 }
@@ -112,7 +185,8 @@ export function buildInitializeIx(
   mint: PublicKey,
   authorityRole: PublicKey,
   transferHookProgram: PublicKey,
-  params: InitializeParams
+  params: InitializeParams,
+  remainingAccounts: AccountMeta[] = []
 ): TransactionInstruction {
   const data = Buffer.concat([
     anchorDiscriminator("initialize"),
@@ -125,22 +199,38 @@ export function buildInitializeIx(
       { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: mint, isSigner: true, isWritable: true },
       { pubkey: authorityRole, isSigner: false, isWritable: true },
+      { pubkey: findIssuerRegistryPDA(authority)[0], isSigner: false, isWritable: true },
       { pubkey: transferHookProgram, isSigner: false, isWritable: false },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      ...remainingAccounts,
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
     data,
   });
 }
 
+export function buildInitIssuerRegistryIx(authority: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: findIssuerRegistryPDA(authority)[0], isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("init_issuer_registry"),
+  });
+}
+
 export interface RoleFlags {
   isMinter: boolean;
   isBurner: boolean;
   isPauser: boolean;
   isBlacklister: boolean;
   isSeizer: boolean;
+  blacklistNamespace?: number;
+  isMetadataUpdater?: boolean;
 }
 
 export function serializeRoleFlags(roles: RoleFlags): Buffer {
@@ -150,6 +240,8 @@ export function serializeRoleFlags(roles: RoleFlags): Buffer {
     roles.isPauser ? 1 : 0,
     roles.isBlacklister ? 1 : 0,
     roles.isSeizer ? 1 : 0,
+    roles.blacklistNamespace ?? 0,
+    roles.isMetadataUpdater ? 1 : 0,
   ]);
 }
 
@@ -168,7 +260,7 @@ export function buildUpdateRolesIx(
   return new TransactionInstruction({
     keys: [
       { pubkey: authority, isSigner: true, isWritable: true },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: true },
       { pubkey: holder, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -183,20 +275,30 @@ export function buildUpdateMinterIx(
   stablecoin: PublicKey,
   minterInfo: PublicKey,
   minter: PublicKey,
-  quota: bigint
+  quota: bigint,
+  epochDuration?: bigint
 ): TransactionInstruction {
   const quotaBuf = Buffer.alloc(8);
   quotaBuf.writeBigUInt64LE(quota);
 
+  // epoch_duration: Option<i64> (None leaves the current setting unchanged)
+  let epochBuf = Buffer.from([0]);
+  if (epochDuration !== undefined) {
+    epochBuf = Buffer.alloc(9);
+    epochBuf.writeUInt8(1, 0);
+    epochBuf.writeBigInt64LE(epochDuration, 1);
+  }
+
   const data = Buffer.concat([
     anchorDiscriminator("update_minter"),
     quotaBuf,
+    epochBuf,
   ]);
 
   return new TransactionInstruction({
     keys: [
       { pubkey: authority, isSigner: true, isWritable: true },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: minterInfo, isSigner: false, isWritable: true },
       { pubkey: minter, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -206,6 +308,138 @@ export function buildUpdateMinterIx(
   });
 }
 
+export interface MinterUpdate {
+  minter: PublicKey;
+  quota: bigint;
+  epochDuration?: bigint;
+}
+
+/** Passes one (minter, minter PDA) pair per update, so `minter_index` is the update's position. */
+export function buildBatchUpdateMinterIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  updates: MinterUpdate[]
+): TransactionInstruction {
+  const entries = updates.map((update, index) => {
+    const buf = Buffer.alloc(update.epochDuration === undefined ? 10 : 18);
+    buf.writeUInt8(index, 0);
+    buf.writeBigUInt64LE(update.quota, 1);
+    if (update.epochDuration !== undefined) {
+      buf.writeUInt8(1, 9);
+      buf.writeBigInt64LE(update.epochDuration, 10);
+    }
+    return buf;
+  });
+
+  const data = Buffer.concat([
+    anchorDiscriminator("batch_update_minter"),
+    Buffer.from(new Uint32Array([updates.length]).buffer),
+    ...entries,
+  ]);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...updates.flatMap((update) => [
+        { pubkey: update.minter, isSigner: false, isWritable: false },
+        { pubkey: findMinterPDA(stablecoin, update.minter)[0], isSigner: false, isWritable: true },
+      ]),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildBulkSetRoleFlagIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  flag: number,
+  value: boolean,
+  roles: PublicKey[]
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      ...roles.map((role) => ({ pubkey: role, isSigner: false, isWritable: true })),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("bulk_set_role_flag"), Buffer.from([flag, value ? 1 : 0])]),
+  });
+}
+
+export function buildRevokeMinterIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  minterInfo: PublicKey,
+  minter: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: true },
+      { pubkey: minterInfo, isSigner: false, isWritable: true },
+      { pubkey: minter, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("revoke_minter"),
+  });
+}
+
+export function buildTransferQuotaIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  fromMinterInfo: PublicKey,
+  toMinterInfo: PublicKey,
+  amount: bigint
+): TransactionInstruction {
+  const amountBuf = Buffer.alloc(8);
+  amountBuf.writeBigUInt64LE(amount);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: fromMinterInfo, isSigner: false, isWritable: true },
+      { pubkey: toMinterInfo, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("transfer_quota"), amountBuf]),
+  });
+}
+
+export function buildSnapshotMinterStatsIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  minterInfo: PublicKey,
+  snapshot: PublicKey,
+  period: bigint,
+  reset: boolean
+): TransactionInstruction {
+  const periodBuf = Buffer.alloc(8);
+  periodBuf.writeBigUInt64LE(period);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: minterInfo, isSigner: false, isWritable: true },
+      { pubkey: snapshot, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([
+      anchorDiscriminator("snapshot_minter_stats"),
+      periodBuf,
+      Buffer.from([reset ? 1 : 0]),
+    ]),
+  });
+}
+
 export function buildMintTokensIx(
   minter: PublicKey,
   stablecoin: PublicKey,
@@ -213,14 +447,20 @@ export function buildMintTokensIx(
   minterInfo: PublicKey,
   mint: PublicKey,
   recipientTokenAccount: PublicKey,
-  amount: bigint
+  amount: bigint,
+  remainingAccounts: AccountMeta[] = [],
+  reference?: Buffer
 ): TransactionInstruction {
   const amountBuf = Buffer.alloc(8);
   amountBuf.writeBigUInt64LE(amount);
 
+  // reference: Option<[u8; 16]>
+  const referenceBuf = reference ? Buffer.concat([Buffer.from([1]), reference]) : Buffer.from([0]);
+
   const data = Buffer.concat([
     anchorDiscriminator("mint_tokens"),
     amountBuf,
+    referenceBuf,
   ]);
 
   return new TransactionInstruction({
@@ -232,6 +472,7 @@ export function buildMintTokensIx(
       { pubkey: mint, isSigner: false, isWritable: true },
       { pubkey: recipientTokenAccount, isSigner: false, isWritable: true },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      ...remainingAccounts,
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
     data,
@@ -268,6 +509,36 @@ export function buildBurnTokensIx(
   });
 }
 
+export function buildBurnFromVaultIx(
+  burner: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  vaultTokenAccount: PublicKey,
+  amount: bigint
+): TransactionInstruction {
+  const amountBuf = Buffer.alloc(8);
+  amountBuf.writeBigUInt64LE(amount);
+
+  const data = Buffer.concat([
+    anchorDiscriminator("burn_from_vault"),
+    amountBuf,
+  ]);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: burner, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: vaultTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
 export function buildPauseIx(
   authority: PublicKey,
   stablecoin: PublicKey,
@@ -300,20 +571,48 @@ export function buildUnpauseIx(
   });
 }
 
+export function buildUpdateMetadataIx(
+  updater: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  name: string,
+  uri: string
+): TransactionInstruction {
+  const nameBytes = Buffer.from(name, "utf-8");
+  const uriBytes = Buffer.from(uri, "utf-8");
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: updater, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([
+      anchorDiscriminator("update_metadata"),
+      Buffer.from(new Uint32Array([nameBytes.length]).buffer),
+      nameBytes,
+      Buffer.from(new Uint32Array([uriBytes.length]).buffer),
+      uriBytes,
+    ]),
+  });
+}
+
 export function buildFreezeAccountIx(
   authority: PublicKey,
   stablecoin: PublicKey,
   role: PublicKey,
   mint: PublicKey,
-  targetTokenAccount: PublicKey
+  targetTokenAccount: PublicKey,
+  freezeAuthority: PublicKey = stablecoin
 ): TransactionInstruction {
   return new TransactionInstruction({
     keys: [
       { pubkey: authority, isSigner: true, isWritable: false },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: false },
       { pubkey: mint, isSigner: false, isWritable: false },
       { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: freezeAuthority, isSigner: false, isWritable: false },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
@@ -326,15 +625,17 @@ export function buildThawAccountIx(
   stablecoin: PublicKey,
   role: PublicKey,
   mint: PublicKey,
-  targetTokenAccount: PublicKey
+  targetTokenAccount: PublicKey,
+  freezeAuthority: PublicKey = stablecoin
 ): TransactionInstruction {
   return new TransactionInstruction({
     keys: [
       { pubkey: authority, isSigner: true, isWritable: false },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: false },
       { pubkey: mint, isSigner: false, isWritable: false },
       { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: freezeAuthority, isSigner: false, isWritable: false },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
@@ -342,6 +643,72 @@ export function buildThawAccountIx(
   });
 }
 
+export function buildGetEffectiveRolesIx(
+  stablecoin: PublicKey,
+  role: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("get_effective_roles"),
+  });
+}
+
+export function buildGetComplianceStatsIx(stablecoin: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [{ pubkey: stablecoin, isSigner: false, isWritable: false }],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("get_compliance_stats"),
+  });
+}
+
+export function buildGetCirculatingSupplyIx(stablecoin: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [{ pubkey: stablecoin, isSigner: false, isWritable: false }],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("get_circulating_supply"),
+  });
+}
+
+export function buildCloseStablecoinIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  mint: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("close_stablecoin"),
+  });
+}
+
+export function buildEmitConfigSnapshotIx(stablecoin: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [{ pubkey: stablecoin, isSigner: false, isWritable: false }],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("emit_config_snapshot"),
+  });
+}
+
+export function buildCheckAuthoritiesIx(stablecoin: PublicKey, mint: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("check_authorities"),
+  });
+}
+
 export function buildTransferAuthorityIx(
   authority: PublicKey,
   stablecoin: PublicKey,
@@ -358,25 +725,223 @@ export function buildTransferAuthorityIx(
   });
 }
 
+export function buildAcceptAuthorityIx(
+  newAuthority: PublicKey,
+  stablecoin: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: newAuthority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("accept_authority"),
+  });
+}
+
+export function buildCancelAuthorityTransferIx(
+  authority: PublicKey,
+  stablecoin: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("cancel_authority_transfer"),
+  });
+}
+
+export function buildClaimRecoveryIx(
+  recoveryAuthority: PublicKey,
+  stablecoin: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: recoveryAuthority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("claim_recovery"),
+  });
+}
+
+/**
+ * Build an authority-gated configuration instruction (shares the
+ * `UpdateConfig` account layout: authority, stablecoin).
+ */
+export function buildUpdateConfigIx(
+  instruction: string,
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  args: Buffer = Buffer.alloc(0)
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator(instruction), args]),
+  });
+}
+
+export function buildInitializeExtraAccountMetaListIx(
+  authority: PublicKey,
+  mint: PublicKey
+): TransactionInstruction {
+  const [extraAccountMetaList] = findExtraAccountMetaListPDA(mint);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_HOOK_PROGRAM_ID,
+    data: Buffer.concat([
+      anchorDiscriminator("initialize_extra_account_meta_list"),
+      SSS_TOKEN_PROGRAM_ID.toBuffer(),
+    ]),
+  });
+}
+
+export function buildUpdateExtraAccountMetaListIx(
+  authority: PublicKey,
+  mint: PublicKey,
+  stablecoin: PublicKey
+): TransactionInstruction {
+  const [extraAccountMetaList] = findExtraAccountMetaListPDA(mint);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_HOOK_PROGRAM_ID,
+    data: anchorDiscriminator("update_extra_account_meta_list"),
+  });
+}
+
+export function buildRepairBumpIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  pdas: PublicKey[] = []
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      ...pdas.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("repair_bump"),
+  });
+}
+
+export function buildMigrateStablecoinIx(authority: PublicKey, stablecoin: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("migrate_stablecoin"),
+  });
+}
+
+export function buildMigrateRoleAccountIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("migrate_role_account"),
+  });
+}
+
+export function buildMigrateMinterInfoIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  minterInfo: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: minterInfo, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("migrate_minter_info"),
+  });
+}
+
+export function buildMigrateBlacklistEntryIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  blacklistEntry: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: blacklistEntry, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("migrate_blacklist_entry"),
+  });
+}
+
+/** `BlacklistReasonCode` variants, as stored on entries and emitted in events */
+export const BLACKLIST_REASON_CODES = {
+  other: 0,
+  sanctions: 1,
+  fraud: 2,
+  courtOrder: 3,
+} as const;
+
 export function buildAddToBlacklistIx(
   blacklister: PublicKey,
   stablecoin: PublicKey,
   role: PublicKey,
   blacklistEntry: PublicKey,
   address: PublicKey,
-  reason: string
+  reason: string,
+  expiresAt?: bigint,
+  reasonCode: number = BLACKLIST_REASON_CODES.other
 ): TransactionInstruction {
   const reasonBytes = Buffer.from(reason, "utf-8");
+  // expires_at: Option<i64> (None = until removed)
+  const expiry = Buffer.alloc(expiresAt === undefined ? 1 : 9);
+  if (expiresAt !== undefined) {
+    expiry[0] = 1;
+    expiry.writeBigInt64LE(expiresAt, 1);
+  }
   const data = Buffer.concat([
     anchorDiscriminator("add_to_blacklist"),
     Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
     reasonBytes,
+    Buffer.from([reasonCode]),
+    expiry,
   ]);
 
   return new TransactionInstruction({
     keys: [
       { pubkey: blacklister, isSigner: true, isWritable: true },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: false },
       { pubkey: blacklistEntry, isSigner: false, isWritable: true },
       { pubkey: address, isSigner: false, isWritable: false },
@@ -387,43 +952,336 @@ export function buildAddToBlacklistIx(
   });
 }
 
+export function buildRemoveFromBlacklistIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  blacklistEntry: PublicKey,
+  address: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: blacklistEntry, isSigner: false, isWritable: true },
+      { pubkey: address, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("remove_from_blacklist"),
+  });
+}
+
+export function buildTryRemoveFromBlacklistIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  address: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: findBlacklistPDA(stablecoin, address)[0], isSigner: false, isWritable: true },
+      { pubkey: address, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("try_remove_from_blacklist"),
+  });
+}
+
+export function buildLockdownIx(
+  responder: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  address: PublicKey,
+  mint: PublicKey,
+  targetTokenAccount: PublicKey,
+  reason: string,
+  freezeAuthority: PublicKey = stablecoin,
+  reasonCode: number = BLACKLIST_REASON_CODES.other
+): TransactionInstruction {
+  const reasonBytes = Buffer.from(reason, "utf-8");
+  const data = Buffer.concat([
+    anchorDiscriminator("lockdown"),
+    Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
+    reasonBytes,
+    Buffer.from([reasonCode]),
+  ]);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: responder, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: findBlacklistPDA(stablecoin, address)[0], isSigner: false, isWritable: true },
+      { pubkey: address, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: freezeAuthority, isSigner: false, isWritable: false },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildImportBlacklistIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  addresses: PublicKey[],
+  reason: string,
+  reasonCode: number = BLACKLIST_REASON_CODES.other
+): TransactionInstruction {
+  const reasonBytes = Buffer.from(reason, "utf-8");
+  const data = Buffer.concat([
+    anchorDiscriminator("import_blacklist"),
+    Buffer.from(new Uint32Array([addresses.length]).buffer),
+    ...addresses.map((address) => address.toBuffer()),
+    Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
+    reasonBytes,
+    Buffer.from([reasonCode]),
+  ]);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...addresses.map((address) => ({
+        pubkey: findBlacklistPDA(stablecoin, address)[0],
+        isSigner: false,
+        isWritable: true,
+      })),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildReassignBlacklistOwnerIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  blacklistEntry: PublicKey,
+  newOwner: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: blacklistEntry, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("reassign_blacklist_owner"), newOwner.toBuffer()]),
+  });
+}
+
 export function buildSeizeIx(
   seizer: PublicKey,
   stablecoin: PublicKey,
   role: PublicKey,
   mint: PublicKey,
   sourceTokenAccount: PublicKey,
-  destinationTokenAccount: PublicKey
+  sourceOwner: PublicKey,
+  destinationTokenAccount: PublicKey,
+  feeTreasury?: PublicKey,
+  amount?: bigint
 ): TransactionInstruction {
+  let data = anchorDiscriminator("seize");
+  if (amount !== undefined) {
+    const amountBuf = Buffer.alloc(8);
+    amountBuf.writeBigUInt64LE(amount);
+    data = Buffer.concat([anchorDiscriminator("seize_amount"), amountBuf]);
+  }
+
   return new TransactionInstruction({
     keys: [
       { pubkey: seizer, isSigner: true, isWritable: false },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: false },
       { pubkey: mint, isSigner: false, isWritable: false },
       { pubkey: sourceTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: findBlacklistPDA(stablecoin, sourceOwner)[0], isSigner: false, isWritable: false },
       { pubkey: destinationTokenAccount, isSigner: false, isWritable: true },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      ...(feeTreasury ? [{ pubkey: feeTreasury, isSigner: false, isWritable: true }] : []),
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("seize"),
+    data,
   });
 }
 
+// ── Account Decoders ───────────────────────────────────────────────
+
+export interface MinterInfoData {
+  quota: bigint;
+  mintedAmount: bigint;
+  epochDuration: bigint;
+  epochStart: bigint;
+  mintedThisEpoch: bigint;
+}
+
+export async function fetchMinterInfo(
+  connection: Connection,
+  minterInfo: PublicKey
+): Promise<MinterInfoData> {
+  const info = await connection.getAccountInfo(minterInfo);
+  if (!info) throw new Error(`MinterInfo ${minterInfo.toBase58()} not found`);
+  // discriminator(8) + stablecoin(32) + minter(32)
+  return {
+    quota: info.data.readBigUInt64LE(72),
+    mintedAmount: info.data.readBigUInt64LE(80),
+    epochDuration: info.data.readBigInt64LE(88),
+    epochStart: info.data.readBigInt64LE(96),
+    mintedThisEpoch: info.data.readBigUInt64LE(104),
+  };
+}
+
+export interface ComplianceStatsData {
+  blacklistEntries: bigint;
+  totalSeized: bigint;
+  seizureCount: bigint;
+}
+
+export async function fetchComplianceStats(
+  connection: Connection,
+  stablecoin: PublicKey,
+  payer: Keypair
+): Promise<ComplianceStatsData> {
+  const returned = await simulateReturnData(connection, buildGetComplianceStatsIx(stablecoin), payer);
+  // Trailing zero bytes are trimmed from return data
+  const data = Buffer.concat([returned ?? Buffer.alloc(0), Buffer.alloc(24)]);
+  return {
+    blacklistEntries: data.readBigUInt64LE(0),
+    totalSeized: data.readBigUInt64LE(8),
+    seizureCount: data.readBigUInt64LE(16),
+  };
+}
+
+/** `total_minted - total_burned`, as reported by `get_circulating_supply`. */
+export async function fetchCirculatingSupply(
+  connection: Connection,
+  stablecoin: PublicKey,
+  payer: Keypair
+): Promise<bigint> {
+  const returned = await simulateReturnData(connection, buildGetCirculatingSupplyIx(stablecoin), payer);
+  // Trailing zero bytes are trimmed from return data
+  return Buffer.concat([returned ?? Buffer.alloc(0), Buffer.alloc(8)]).readBigUInt64LE(0);
+}
+
+export interface BlacklistEntryData {
+  address: PublicKey;
+  reason: string;
+  blacklistedAt: bigint;
+  blacklistedBy: PublicKey;
+  reasonCode: number;
+}
+
+export async function fetchBlacklistEntry(
+  connection: Connection,
+  blacklistEntry: PublicKey
+): Promise<BlacklistEntryData> {
+  const info = await connection.getAccountInfo(blacklistEntry);
+  if (!info) throw new Error(`BlacklistEntry ${blacklistEntry.toBase58()} not found`);
+  // discriminator(8) + stablecoin(32) + address(32) + reason(4 + len)
+  const reasonLen = info.data.readUInt32LE(72);
+  const afterReason = 76 + reasonLen;
+  // blacklisted_at(8) + blacklisted_by(32) + bump(1) + namespace(1), then expires_at: Option<i64>
+  const expiresAtOffset = afterReason + 42;
+  const reasonCodeOffset = expiresAtOffset + (info.data[expiresAtOffset] === 0 ? 1 : 9);
+  return {
+    address: new PublicKey(info.data.subarray(40, 72)),
+    reason: info.data.subarray(76, afterReason).toString("utf-8"),
+    blacklistedAt: info.data.readBigInt64LE(afterReason),
+    blacklistedBy: new PublicKey(info.data.subarray(afterReason + 8, afterReason + 40)),
+    reasonCode: info.data[reasonCodeOffset],
+  };
+}
+
+// ── Assertions ─────────────────────────────────────────────────────
+
+/**
+ * Assert that a transaction fails and that the error (or its program logs)
+ * mentions the expected Anchor error name.
+ */
+export async function expectTxError(
+  promise: Promise<unknown>,
+  errorName: string
+): Promise<void> {
+  try {
+    await promise;
+  } catch (err: any) {
+    const logs: string[] = err?.logs ?? err?.transactionLogs ?? [];
+    const text = [String(err), ...logs].join("\n");
+    if (!text.includes(errorName)) {
+      throw new Error(`expected error "${errorName}", got: ${text}`);
+    }
+    return;
+  }
+  throw new Error(`expected error "${errorName}", but the transaction succeeded`);
+}
+
+/** Returns the payloads of all Anchor events named `eventName` emitted by a transaction. */
+export async function fetchEvents(
+  connection: Connection,
+  signature: string,
+  eventName: string
+): Promise<Buffer[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const disc = crypto.createHash("sha256").update(`event:${eventName}`).digest().subarray(0, 8);
+  return (tx?.meta?.logMessages ?? [])
+    .filter((line) => line.startsWith("Program data: "))
+    .map((line) => Buffer.from(line.slice("Program data: ".length), "base64"))
+    .filter((data) => data.subarray(0, 8).equals(disc))
+    .map((data) => data.subarray(8));
+}
+
+/** Returns the program return data set by a transaction, if any. */
+export async function fetchReturnData(
+  connection: Connection,
+  signature: string
+): Promise<Buffer | null> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const returnData = tx?.meta?.returnData;
+  return returnData ? Buffer.from(returnData.data[0], "base64") : null;
+}
+
+/** Simulates a read-only instruction and returns the program return data. */
+export async function simulateReturnData(
+  connection: Connection,
+  ix: TransactionInstruction,
+  payer: Keypair
+): Promise<Buffer | null> {
+  const { value } = await connection.simulateTransaction(new Transaction().add(ix), [payer]);
+  if (value.err) throw new Error(`simulation failed: ${JSON.stringify(value.err)}\n${value.logs?.join("\n")}`);
+  return value.returnData ? Buffer.from(value.returnData.data[0], "base64") : null;
+}
+
 // ── Token-2022 Helpers ─────────────────────────────────────────────
 
 export async function createTokenAccount(
   connection: Connection,
   payer: Keypair,
   mint: PublicKey,
-  owner: PublicKey
+  owner: PublicKey,
+  allowOwnerOffCurve = false
 ): Promise<PublicKey> {
   const { createAssociatedTokenAccountInstruction, getAssociatedTokenAddress } =
     await import("@solana/spl-token");
   const ata = await getAssociatedTokenAddress(
     mint,
     owner,
-    false,
+    allowOwnerOffCurve,
     TOKEN_2022_PROGRAM_ID
   );
 
@@ -0,0 +1,436 @@
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  sendAndConfirmTransaction,
+  Transaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  SSS_HOOK_PROGRAM_ID,
+  findStablecoinPDA,
+  findRolePDA,
+  findMinterPDA,
+  findMinterStatsPDA,
+  buildInitializeIx,
+  buildUpdateMinterIx,
+  buildBatchUpdateMinterIx,
+  buildUpdateRolesIx,
+  buildTransferQuotaIx,
+  buildRevokeMinterIx,
+  buildBulkSetRoleFlagIx,
+  buildSnapshotMinterStatsIx,
+  buildUpdateConfigIx,
+  buildFreezeAccountIx,
+  buildMintTokensIx,
+  createTokenAccount,
+  expectTxError,
+  fetchMinterInfo,
+  fetchEvents,
+} from "./helpers";
+
+describe("Minter quota management", () => {
+  const connection = new Connection("http://localhost:8899", "confirmed");
+
+  let authority: Keypair;
+  let mintKeypair: Keypair;
+  let deskA: Keypair;
+  let deskB: Keypair;
+
+  before(async () => {
+    authority = Keypair.generate();
+    mintKeypair = Keypair.generate();
+    deskA = Keypair.generate();
+    deskB = Keypair.generate();
+
+    const sig = await connection.requestAirdrop(authority.publicKey, 10 * LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig);
+
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const initIx = buildInitializeIx(
+      authority.publicKey,
+      stablecoinPDA,
+      mintKeypair.publicKey,
+      authorityRole,
+      SSS_HOOK_PROGRAM_ID,
+      {
+        name: "Desk USD",
+        symbol: "DUSD",
+        uri: "",
+        decimals: 6,
+        enablePermanentDelegate: false,
+        enableTransferHook: false,
+        defaultAccountFrozen: false,
+      }
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(initIx), [authority, mintKeypair]);
+  });
+
+  it("moves quota between two minters and conserves the total", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [infoA] = findMinterPDA(stablecoinPDA, deskA.publicKey);
+    const [infoB] = findMinterPDA(stablecoinPDA, deskB.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, infoA, deskA.publicKey, BigInt(1_000)),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, infoB, deskB.publicKey, BigInt(500))
+      ),
+      [authority]
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildTransferQuotaIx(authority.publicKey, stablecoinPDA, infoA, infoB, BigInt(300))),
+      [authority]
+    );
+
+    const a = await fetchMinterInfo(connection, infoA);
+    const b = await fetchMinterInfo(connection, infoB);
+    expect(a.quota).to.equal(BigInt(700));
+    expect(b.quota).to.equal(BigInt(800));
+    expect(a.quota + b.quota).to.equal(BigInt(1_500));
+  });
+
+  it("revokes a minter, closing its MinterInfo and refunding the rent", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const desk = Keypair.generate().publicKey;
+    const neverConfigured = Keypair.generate().publicKey;
+    const [role] = findRolePDA(stablecoinPDA, desk);
+    const [info] = findMinterPDA(stablecoinPDA, desk);
+    const [otherRole] = findRolePDA(stablecoinPDA, neverConfigured);
+    const [otherInfo] = findMinterPDA(stablecoinPDA, neverConfigured);
+    const minterFlags = { isMinter: true, isBurner: true, isPauser: false, isBlacklister: false, isSeizer: false };
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, role, desk, minterFlags),
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, otherRole, neverConfigured, minterFlags),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, info, desk, BigInt(1_000))
+      ),
+      [authority]
+    );
+
+    const rent = (await connection.getAccountInfo(info))!.lamports;
+    const before = await connection.getBalance(authority.publicKey);
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildRevokeMinterIx(authority.publicKey, stablecoinPDA, role, info, desk)),
+      [authority]
+    );
+    expect(await connection.getBalance(authority.publicKey)).to.equal(before + rent - 5_000);
+    expect(await connection.getAccountInfo(info)).to.be.null;
+
+    // role account: disc(8) + stablecoin(32) + holder(32), then is_minter, is_burner
+    const roleData = (await connection.getAccountInfo(role))!.data;
+    expect(roleData[72]).to.equal(0);
+    expect(roleData[73]).to.equal(1);
+
+    const [event] = await fetchEvents(connection, sig, "MinterRevoked");
+    expect(event.subarray(32, 64).equals(desk.toBuffer())).to.be.true;
+    expect(event.subarray(64, 96).equals(authority.publicKey.toBuffer())).to.be.true;
+
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildRevokeMinterIx(authority.publicKey, stablecoinPDA, otherRole, otherInfo, neverConfigured)
+        ),
+        [authority]
+      ),
+      "AccountNotInitialized"
+    );
+  });
+
+  it("strips the minter role from several holders in one call", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const desks = [Keypair.generate().publicKey, Keypair.generate().publicKey, Keypair.generate().publicKey];
+    const roles = desks.map((desk) => findRolePDA(stablecoinPDA, desk)[0]);
+    const MINTER = 1 << 0;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        ...desks.map((desk, i) =>
+          buildUpdateRolesIx(authority.publicKey, stablecoinPDA, roles[i], desk, {
+            isMinter: true,
+            isBurner: true,
+            isPauser: false,
+            isBlacklister: false,
+            isSeizer: false,
+          })
+        )
+      ),
+      [authority]
+    );
+
+    const bulk = (flag: number) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildBulkSetRoleFlagIx(authority.publicKey, stablecoinPDA, flag, false, roles)),
+        [authority]
+      );
+    // Exactly one role bit must be named
+    await expectTxError(bulk(MINTER | (1 << 1)), "InvalidRoleConfig");
+
+    const sig = await bulk(MINTER);
+    expect(await fetchEvents(connection, sig, "RolesUpdated")).to.have.length(3);
+    for (const role of roles) {
+      // role account: disc(8) + stablecoin(32) + holder(32), then is_minter, is_burner
+      const data = (await connection.getAccountInfo(role))!.data;
+      expect(data[72]).to.equal(0);
+      expect(data[73]).to.equal(1);
+    }
+  });
+
+  it("leaves counters untouched when the mint CPI fails", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [roleA] = findRolePDA(stablecoinPDA, deskA.publicKey);
+    const [infoA] = findMinterPDA(stablecoinPDA, deskA.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, roleA, deskA.publicKey, {
+          isMinter: true, isBurner: false, isPauser: false, isBlacklister: false, isSeizer: false,
+        })
+      ),
+      [authority]
+    );
+
+    // A frozen recipient makes Token-2022 reject mint_to after our counters moved
+    const frozenATA = await createTokenAccount(
+      connection, authority, mintKeypair.publicKey, Keypair.generate().publicKey
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildFreezeAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, frozenATA)
+      ),
+      [authority]
+    );
+
+    const before = await fetchMinterInfo(connection, infoA);
+    const airdrop = await connection.requestAirdrop(deskA.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(deskA.publicKey, stablecoinPDA, roleA, infoA, mintKeypair.publicKey, frozenATA, BigInt(100))
+        ),
+        [deskA]
+      ),
+      "frozen"
+    );
+
+    const after = await fetchMinterInfo(connection, infoA);
+    expect(after.mintedAmount).to.equal(before.mintedAmount);
+  });
+
+  it("emits MinterQuotaExhausted only on the mint that reaches the quota", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [roleB] = findRolePDA(stablecoinPDA, deskB.publicKey);
+    const [infoB] = findMinterPDA(stablecoinPDA, deskB.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, roleB, deskB.publicKey, {
+          isMinter: true, isBurner: false, isPauser: false, isBlacklister: false, isSeizer: false,
+        }),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, infoB, deskB.publicKey, BigInt(250))
+      ),
+      [authority]
+    );
+    const airdrop = await connection.requestAirdrop(deskB.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    const recipientATA = await createTokenAccount(
+      connection, authority, mintKeypair.publicKey, Keypair.generate().publicKey
+    );
+    const mint = (amount: number) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(deskB.publicKey, stablecoinPDA, roleB, infoB, mintKeypair.publicKey, recipientATA, BigInt(amount))
+        ),
+        [deskB]
+      );
+
+    const partial = await mint(100);
+    expect(await fetchEvents(connection, partial, "MinterQuotaExhausted")).to.have.length(0);
+
+    const exhausting = await mint(150);
+    const events = await fetchEvents(connection, exhausting, "MinterQuotaExhausted");
+    expect(events).to.have.length(1);
+    // stablecoin (32) + minter (32) + quota (8) + minted_amount (8) + timestamp (8)
+    expect(events[0].subarray(32, 64).equals(deskB.publicKey.toBuffer())).to.be.true;
+    expect(events[0].readBigUInt64LE(64)).to.equal(BigInt(250));
+    expect(events[0].readBigUInt64LE(72)).to.equal(BigInt(250));
+
+    await expectTxError(mint(1), "QuotaExceeded");
+  });
+
+  it("validates epoch_duration bounds", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const desk = Keypair.generate().publicKey;
+    const [info] = findMinterPDA(stablecoinPDA, desk);
+    const update = (epochDuration: bigint) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateMinterIx(authority.publicKey, stablecoinPDA, info, desk, BigInt(1_000), epochDuration)
+        ),
+        [authority]
+      );
+
+    await expectTxError(update(BigInt(-1)), "InvalidEpochDuration");
+    await expectTxError(update(BigInt(59)), "InvalidEpochDuration");
+
+    await update(BigInt(86_400));
+    let state = await fetchMinterInfo(connection, info);
+    expect(state.epochDuration).to.equal(BigInt(86_400));
+    expect(state.epochStart > BigInt(0)).to.be.true;
+
+    // 0 switches back to a lifetime quota
+    await update(BigInt(0));
+    state = await fetchMinterInfo(connection, info);
+    expect(state.epochDuration).to.equal(BigInt(0));
+  });
+
+  it("configures several minters in one batch", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const desks = [Keypair.generate(), Keypair.generate(), Keypair.generate()].map((k) => k.publicKey);
+    const updates = [
+      { minter: desks[0], quota: BigInt(1_000), epochDuration: BigInt(3_600) },
+      { minter: desks[1], quota: BigInt(2_000), epochDuration: BigInt(86_400) },
+      { minter: desks[2], quota: BigInt(3_000) },
+    ];
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildBatchUpdateMinterIx(authority.publicKey, stablecoinPDA, updates)),
+      [authority]
+    );
+
+    for (const update of updates) {
+      const state = await fetchMinterInfo(connection, findMinterPDA(stablecoinPDA, update.minter)[0]);
+      expect(state.quota).to.equal(update.quota);
+      expect(state.epochDuration).to.equal(update.epochDuration ?? BigInt(0));
+    }
+
+    // Existing minters are updated in place
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildBatchUpdateMinterIx(authority.publicKey, stablecoinPDA, [{ minter: desks[0], quota: BigInt(5_000) }])
+      ),
+      [authority]
+    );
+    const state = await fetchMinterInfo(connection, findMinterPDA(stablecoinPDA, desks[0])[0]);
+    expect(state.quota).to.equal(BigInt(5_000));
+    expect(state.epochDuration).to.equal(BigInt(3_600));
+  });
+
+  it("snapshots a minter's usage for a period and resets only epoch counters", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [infoB] = findMinterPDA(stablecoinPDA, deskB.publicKey);
+    const snapshot = (period: bigint, reset: boolean) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSnapshotMinterStatsIx(
+            authority.publicKey, stablecoinPDA, infoB, findMinterStatsPDA(stablecoinPDA, deskB.publicKey, period)[0],
+            period, reset
+          )
+        ),
+        [authority]
+      );
+
+    const period = BigInt(202603);
+    const [snapshotPDA] = findMinterStatsPDA(stablecoinPDA, deskB.publicKey, period);
+    const before = await fetchMinterInfo(connection, infoB);
+    expect(before.mintedAmount > BigInt(0)).to.be.true;
+    expect(before.epochDuration).to.equal(BigInt(0));
+
+    // Without epochs minted_amount is the quota counter; resetting it would free quota
+    await expectTxError(snapshot(period, true), "ResetWouldFreeQuota");
+    await snapshot(period, false);
+
+    const record = (await connection.getAccountInfo(snapshotPDA))!.data;
+    // discriminator(8) + stablecoin(32) + minter(32) + period(8) + quota(8)
+    expect(record.subarray(40, 72).equals(deskB.publicKey.toBuffer())).to.be.true;
+    expect(record.readBigUInt64LE(72)).to.equal(period);
+    expect(record.readBigUInt64LE(80)).to.equal(before.quota);
+    expect(record.readBigUInt64LE(88)).to.equal(before.mintedAmount);
+    expect(record[104]).to.equal(0);
+    expect((await fetchMinterInfo(connection, infoB)).mintedAmount).to.equal(before.mintedAmount);
+
+    // A closed period cannot be overwritten
+    await expectTxError(snapshot(period, false), "already in use");
+
+    // With epochs the quota runs on minted_this_epoch, so the live total may be reset
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, infoB, deskB.publicKey, before.quota, BigInt(3_600))
+      ),
+      [authority]
+    );
+    const epochBefore = await fetchMinterInfo(connection, infoB);
+    await snapshot(period + BigInt(1), true);
+    const after = await fetchMinterInfo(connection, infoB);
+    expect(after.mintedAmount).to.equal(BigInt(0));
+    expect(after.mintedThisEpoch).to.equal(epochBefore.mintedThisEpoch);
+    expect(after.quota).to.equal(before.quota);
+  });
+
+  it("aligns epoch starts to wall-clock boundaries when configured", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const day = BigInt(86_400);
+    const setAlignment = (offset: bigint | null) => {
+      const data = Buffer.alloc(offset === null ? 1 : 9);
+      if (offset !== null) {
+        data[0] = 1;
+        data.writeBigInt64LE(offset, 1);
+      }
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateConfigIx("set_epoch_alignment", authority.publicKey, stablecoinPDA, data)),
+        [authority]
+      );
+    };
+    const startDailyEpoch = async () => {
+      const desk = Keypair.generate().publicKey;
+      const [info] = findMinterPDA(stablecoinPDA, desk);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateMinterIx(authority.publicKey, stablecoinPDA, info, desk, BigInt(1_000), day)
+        ),
+        [authority]
+      );
+      return (await fetchMinterInfo(connection, info)).epochStart;
+    };
+
+    await expectTxError(setAlignment(BigInt(-1)), "InvalidEpochAlignment");
+
+    // 00:00 UTC
+    await setAlignment(BigInt(0));
+    const midnight = await startDailyEpoch();
+    const now = BigInt((await connection.getBlockTime(await connection.getSlot()))!);
+    expect(midnight % day).to.equal(BigInt(0));
+    expect(now - midnight >= BigInt(0) && now - midnight < day).to.be.true;
+
+    // 01:00 UTC
+    await setAlignment(BigInt(3_600));
+    expect((await startDailyEpoch()) % day).to.equal(BigInt(3_600));
+
+    await setAlignment(null);
+  });
+});
@@ -2,27 +2,52 @@ import {
   Connection,
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   sendAndConfirmTransaction,
   Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
 import { expect } from "chai";
+import { getAssociatedTokenAddress, getAccount, getMint } from "@solana/spl-token";
 import {
   SSS_TOKEN_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
   findStablecoinPDA,
   findRolePDA,
   findMinterPDA,
+  findFreezeAuthorityPDA,
   buildInitializeIx,
   buildUpdateRolesIx,
   buildUpdateMinterIx,
   buildMintTokensIx,
   buildBurnTokensIx,
+  buildBurnFromVaultIx,
   buildPauseIx,
   buildUnpauseIx,
   buildFreezeAccountIx,
   buildThawAccountIx,
   buildTransferAuthorityIx,
+  buildAcceptAuthorityIx,
+  buildCancelAuthorityTransferIx,
+  buildClaimRecoveryIx,
+  buildUpdateConfigIx,
+  buildRepairBumpIx,
+  buildMigrateMinterInfoIx,
+  buildMigrateRoleAccountIx,
+  buildMigrateStablecoinIx,
+  buildUpdateMetadataIx,
+  buildInitIssuerRegistryIx,
+  findIssuerRegistryPDA,
+  buildGetEffectiveRolesIx,
+  buildCheckAuthoritiesIx,
+  buildCloseStablecoinIx,
+  buildEmitConfigSnapshotIx,
+  fetchCirculatingSupply,
   createTokenAccount,
+  expectTxError,
+  simulateReturnData,
+  fetchEvents,
+  fetchReturnData,
   SSS_HOOK_PROGRAM_ID,
 } from "./helpers";
 
@@ -101,6 +126,64 @@ describe("SSS-1: Minimal Stablecoin Lifecycle", () => {
     expect(info).to.not.be.null;
   });
 
+  it("reports effective roles as a bitfield", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const effectiveRoles = async (role: typeof minterRole) =>
+      // trailing zero bytes of return data are trimmed by the runtime
+      (await simulateReturnData(connection, buildGetEffectiveRolesIx(stablecoinPDA, role), authority))?.[0] ?? 0;
+
+    // minter | burner | pauser | blacklister | seizer | metadata updater
+    expect(await effectiveRoles(authorityRole)).to.equal(0b111111);
+    expect(await effectiveRoles(minterRole)).to.equal(0b00001);
+
+    // A revoked holder keeps its PDA but has no effective roles
+    const revoked = Keypair.generate().publicKey;
+    const [revokedRole] = findRolePDA(stablecoinPDA, revoked);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, revokedRole, revoked, {
+          isMinter: false, isBurner: false, isPauser: false, isBlacklister: false, isSeizer: false,
+        })
+      ),
+      [authority]
+    );
+    expect(await effectiveRoles(revokedRole)).to.equal(0);
+  });
+
+  it("caps the number of roles one holder can accumulate", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const holder = Keypair.generate().publicKey;
+    const [holderRole] = findRolePDA(stablecoinPDA, holder);
+    const setMaxRoles = (max: number) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx("set_max_roles_per_holder", authority.publicKey, stablecoinPDA, Buffer.from([max]))
+        ),
+        [authority]
+      );
+    const grant = (roles: { isMinter: boolean; isBurner: boolean; isPauser: boolean }) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateRolesIx(authority.publicKey, stablecoinPDA, holderRole, holder, {
+            ...roles, isBlacklister: false, isSeizer: false,
+          })
+        ),
+        [authority]
+      );
+
+    await setMaxRoles(2);
+    await expectTxError(grant({ isMinter: true, isBurner: true, isPauser: true }), "TooManyRoles");
+    await grant({ isMinter: true, isBurner: true, isPauser: false });
+
+    await setMaxRoles(0);
+    await grant({ isMinter: true, isBurner: true, isPauser: true });
+  });
+
   it("sets minter quota", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
     const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
@@ -120,6 +203,110 @@ describe("SSS-1: Minimal Stablecoin Lifecycle", () => {
     expect(info).to.not.be.null;
   });
 
+  it("repair_bump leaves canonical bumps alone and rejects foreign accounts", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildRepairBumpIx(authority.publicKey, stablecoinPDA, [authorityRole, minterRole, minterInfo])
+      ),
+      [authority]
+    );
+    const repaired = await fetchReturnData(connection, sig);
+    expect(repaired?.[0] ?? 0).to.equal(0);
+
+    // The mint is not one of this stablecoin's PDAs
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildRepairBumpIx(authority.publicKey, stablecoinPDA, [mintKeypair.publicKey])),
+        [authority]
+      ),
+      "NotRepairable"
+    );
+  });
+
+  it("migrate_stablecoin keeps current-layout stablecoin accounts intact", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const before = (await connection.getAccountInfo(stablecoinPDA))!;
+
+    // Only the authority may migrate
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMigrateStablecoinIx(minterKeypair.publicKey, stablecoinPDA)),
+        [minterKeypair]
+      ),
+      "Unauthorized"
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMigrateStablecoinIx(authority.publicKey, stablecoinPDA)),
+      [authority]
+    );
+
+    const after = (await connection.getAccountInfo(stablecoinPDA))!;
+    expect(after.data.equals(before.data)).to.be.true;
+  });
+
+  it("migrate_role_account keeps current-layout role accounts intact", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const before = (await connection.getAccountInfo(minterRole))!;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMigrateRoleAccountIx(authority.publicKey, stablecoinPDA, minterRole)),
+      [authority]
+    );
+
+    const after = (await connection.getAccountInfo(minterRole))!;
+    expect(after.data.length).to.equal(before.data.length);
+    expect(after.data.equals(before.data)).to.be.true;
+
+    // Only role PDAs of this stablecoin can be migrated
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMigrateRoleAccountIx(authority.publicKey, stablecoinPDA, minterInfo)),
+        [authority]
+      ),
+      "AccountDidNotDeserialize"
+    );
+  });
+
+  it("migrate_minter_info keeps current-layout minter accounts intact", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const before = (await connection.getAccountInfo(minterInfo))!;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMigrateMinterInfoIx(authority.publicKey, stablecoinPDA, minterInfo)),
+      [authority]
+    );
+
+    const after = (await connection.getAccountInfo(minterInfo))!;
+    expect(after.data.equals(before.data)).to.be.true;
+
+    // Only minter PDAs of this stablecoin can be migrated
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMigrateMinterInfoIx(authority.publicKey, stablecoinPDA, minterRole)),
+        [authority]
+      ),
+      "AccountDiscriminatorMismatch"
+    );
+  });
+
   it("mints tokens", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
     const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
@@ -150,6 +337,147 @@ describe("SSS-1: Minimal Stablecoin Lifecycle", () => {
     expect(balance.value.amount).to.equal("500000");
   });
 
+  it("rejects minting into a token account of another mint", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+
+    // A token account for an unrelated Token-2022 mint
+    const otherMint = Keypair.generate();
+    const [otherStablecoin] = findStablecoinPDA(otherMint.publicKey);
+    const [otherRole] = findRolePDA(otherStablecoin, authority.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, otherStablecoin, otherMint.publicKey, otherRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Other USD",
+          symbol: "OUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, otherMint]
+    );
+    const wrongATA = await createTokenAccount(connection, authority, otherMint.publicKey, recipientKeypair.publicKey);
+
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(
+            minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, wrongATA, BigInt(1)
+          )
+        ),
+        [minterKeypair]
+      ),
+      "RecipientMintMismatch"
+    );
+  });
+
+  it("suppresses supply events while masked", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const recipientATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, recipientKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const setMask = (mask: number) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx("set_event_mask", authority.publicKey, stablecoinPDA, Buffer.from([mask]))
+        ),
+        [authority]
+      );
+    const mint = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(
+            minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, recipientATA, BigInt(1)
+          )
+        ),
+        [minterKeypair]
+      );
+
+    await expectTxError(setMask(0b1000), "InvalidEventMask");
+
+    await setMask(0b001); // EVENT_SUPPLY
+    expect(await fetchEvents(connection, await mint(), "TokensMinted")).to.have.length(0);
+
+    await setMask(0);
+    expect(await fetchEvents(connection, await mint(), "TokensMinted")).to.have.length(1);
+  });
+
+  it("echoes the client reference in TokensMinted", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const recipientATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, recipientKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const reference = Buffer.from("0123456789abcdef0123456789abcdef", "hex");
+
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildMintTokensIx(
+          minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, recipientATA, BigInt(1),
+          [], reference
+        )
+      ),
+      [minterKeypair]
+    );
+
+    const events = await fetchEvents(connection, sig, "TokensMinted");
+    expect(events).to.have.length(1);
+    // stablecoin, minter, recipient, amount, total_minted, timestamp, then Option<[u8; 16]>
+    expect(events[0][120]).to.equal(1);
+    expect(events[0].subarray(121, 137).equals(reference)).to.be.true;
+  });
+
+  it("enforces the max_single_mint ceiling", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const recipientATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, recipientKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const setCeiling = (amount: bigint) => {
+      const data = Buffer.alloc(8);
+      data.writeBigUInt64LE(amount);
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx("set_max_single_mint", authority.publicKey, stablecoinPDA, data)
+        ),
+        [authority]
+      );
+    };
+    const mint = (amount: bigint) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(
+            minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, recipientATA, amount
+          )
+        ),
+        [minterKeypair]
+      );
+
+    await setCeiling(BigInt(1_000));
+    await expectTxError(mint(BigInt(1_001)), "MintAmountTooLarge");
+    await mint(BigInt(1_000));
+    await mint(BigInt(999));
+
+    // 0 lifts the ceiling again
+    await setCeiling(BigInt(0));
+    await mint(BigInt(1_001));
+  });
+
   it("assigns burner role and burns tokens", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
 
@@ -202,6 +530,91 @@ describe("SSS-1: Minimal Stablecoin Lifecycle", () => {
 
     const balance = await connection.getTokenAccountBalance(burnerATA);
     expect(balance.value.amount).to.equal("50000");
+
+    // The burn CPI cannot be redirected to another program
+    const redirected = buildBurnTokensIx(
+      burnerKeypair.publicKey, stablecoinPDA, burnerRole, mintKeypair.publicKey, burnerATA, BigInt(1_000)
+    );
+    redirected.keys[5].pubkey = SSS_HOOK_PROGRAM_ID;
+    await expectTxError(
+      sendAndConfirmTransaction(connection, new Transaction().add(redirected), [burnerKeypair]),
+      "ConstraintAddress"
+    );
+    expect((await connection.getTokenAccountBalance(burnerATA)).value.amount).to.equal("50000");
+  });
+
+  it("burns from a PDA-owned redemption vault", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [burnerRole] = findRolePDA(stablecoinPDA, burnerKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+
+    // Vault is an ATA whose owner is the stablecoin PDA (off-curve)
+    const vaultATA = await createTokenAccount(
+      connection,
+      authority,
+      mintKeypair.publicKey,
+      stablecoinPDA,
+      true
+    );
+
+    const mintIx = buildMintTokensIx(
+      minterKeypair.publicKey,
+      stablecoinPDA,
+      minterRole,
+      minterInfo,
+      mintKeypair.publicKey,
+      vaultATA,
+      BigInt(40_000)
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(mintIx), [minterKeypair]);
+
+    // Burner is not the vault owner, yet can redeem from it
+    const burnIx = buildBurnFromVaultIx(
+      burnerKeypair.publicKey,
+      stablecoinPDA,
+      burnerRole,
+      mintKeypair.publicKey,
+      vaultATA,
+      BigInt(15_000)
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(burnIx), [burnerKeypair]);
+
+    const balance = await connection.getTokenAccountBalance(vaultATA);
+    expect(balance.value.amount).to.equal("25000");
+  });
+
+  it("restricts burns to designated redemption accounts", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [burnerRole] = findRolePDA(stablecoinPDA, burnerKeypair.publicKey);
+    const burnerATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, burnerKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const configure = (instruction: string, args: Buffer) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateConfigIx(instruction, authority.publicKey, stablecoinPDA, args)),
+        [authority]
+      );
+    const burn = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildBurnTokensIx(burnerKeypair.publicKey, stablecoinPDA, burnerRole, mintKeypair.publicKey, burnerATA, BigInt(1_000))
+        ),
+        [burnerKeypair]
+      );
+
+    await configure("set_restrict_redemptions", Buffer.from([1]));
+    await expectTxError(burn(), "RedemptionAccountNotAllowed");
+
+    await configure("add_redemption_account", burnerATA.toBuffer());
+    await burn();
+    const balance = await connection.getTokenAccountBalance(burnerATA);
+    expect(balance.value.amount).to.equal("49000");
+
+    await configure("remove_redemption_account", burnerATA.toBuffer());
+    await configure("set_restrict_redemptions", Buffer.from([0]));
   });
 
   it("pauses and unpause the stablecoin", async () => {
@@ -212,6 +625,47 @@ describe("SSS-1: Minimal Stablecoin Lifecycle", () => {
     const pauseIx = buildPauseIx(authority.publicKey, stablecoinPDA, authorityRole);
     await sendAndConfirmTransaction(connection, new Transaction().add(pauseIx), [authority]);
 
+    // Minting and burning are both rejected while paused
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const [burnerRole] = findRolePDA(stablecoinPDA, burnerKeypair.publicKey);
+    const burnerATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, burnerKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, burnerATA, BigInt(1))
+        ),
+        [minterKeypair]
+      ),
+      "Paused"
+    );
+    const burn = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildBurnTokensIx(burnerKeypair.publicKey, stablecoinPDA, burnerRole, mintKeypair.publicKey, burnerATA, BigInt(1))
+        ),
+        [burnerKeypair]
+      );
+    await expectTxError(burn(), "Paused");
+
+    // Redemptions can be kept open through a pause
+    const allowBurn = (allowed: boolean) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx("set_allow_burn_when_paused", authority.publicKey, stablecoinPDA, Buffer.from([allowed ? 1 : 0]))
+        ),
+        [authority]
+      );
+    await allowBurn(true);
+    await burn();
+    expect((await connection.getTokenAccountBalance(burnerATA)).value.amount).to.equal("48999");
+    await allowBurn(false);
+
     // Unpause
     const unpauseIx = buildUnpauseIx(authority.publicKey, stablecoinPDA, authorityRole);
     await sendAndConfirmTransaction(connection, new Transaction().add(unpauseIx), [authority]);
@@ -249,14 +703,912 @@ describe("SSS-1: Minimal Stablecoin Lifecycle", () => {
     await sendAndConfirmTransaction(connection, new Transaction().add(thawIx), [authority]);
   });
 
+  it("refuses to freeze a protected account", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const treasuryATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, authority.publicKey);
+    const setProtected = (instruction: string) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx(instruction, authority.publicKey, stablecoinPDA, treasuryATA.toBuffer())
+        ),
+        [authority]
+      );
+    const freeze = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildFreezeAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, treasuryATA)
+        ),
+        [authority]
+      );
+
+    await setProtected("add_freeze_protected");
+    await expectTxError(freeze(), "FreezeProtected");
+
+    await setProtected("remove_freeze_protected");
+    await freeze();
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, treasuryATA)
+      ),
+      [authority]
+    );
+  });
+
+  it("reports all mint authorities as controlled", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const diverged = await simulateReturnData(
+      connection,
+      buildCheckAuthoritiesIx(stablecoinPDA, mintKeypair.publicKey),
+      authority
+    );
+    // 0 = all controlled (trailing zero bytes of return data are trimmed)
+    expect(diverged?.[0] ?? 0).to.equal(0);
+  });
+
+  it("rejects a mint that does not match the stablecoin", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const recipientATA = await createTokenAccount(
+      connection,
+      authority,
+      mintKeypair.publicKey,
+      Keypair.generate().publicKey
+    );
+
+    const freezeIx = buildFreezeAccountIx(
+      authority.publicKey,
+      stablecoinPDA,
+      authorityRole,
+      Keypair.generate().publicKey, // wrong mint
+      recipientATA
+    );
+    await expectTxError(
+      sendAndConfirmTransaction(connection, new Transaction().add(freezeIx), [authority]),
+      "MintMismatch"
+    );
+  });
+
+  it("freezes through a separate freeze-authority PDA", async () => {
+    const sepMint = Keypair.generate();
+    const [sepStablecoin] = findStablecoinPDA(sepMint.publicKey);
+    const [sepRole] = findRolePDA(sepStablecoin, authority.publicKey);
+    const [freezeAuthority] = findFreezeAuthorityPDA(sepMint.publicKey);
+
+    const initIx = buildInitializeIx(
+      authority.publicKey,
+      sepStablecoin,
+      sepMint.publicKey,
+      sepRole,
+      SSS_HOOK_PROGRAM_ID,
+      {
+        name: "Split USD",
+        symbol: "SUSD",
+        uri: "",
+        decimals: 6,
+        enablePermanentDelegate: false,
+        enableTransferHook: false,
+        defaultAccountFrozen: false,
+        separateFreezeAuthority: true,
+      }
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(initIx), [authority, sepMint]);
+
+    const mintInfo = await getMint(connection, sepMint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(mintInfo.freezeAuthority!.equals(freezeAuthority)).to.be.true;
+    expect(mintInfo.mintAuthority!.equals(sepStablecoin)).to.be.true;
+
+    const holderATA = await createTokenAccount(
+      connection,
+      authority,
+      sepMint.publicKey,
+      Keypair.generate().publicKey
+    );
+
+    // The stablecoin PDA no longer holds freeze authority
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildFreezeAccountIx(authority.publicKey, sepStablecoin, sepRole, sepMint.publicKey, holderATA)
+        ),
+        [authority]
+      ),
+      "InvalidFreezeAuthority"
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildFreezeAccountIx(authority.publicKey, sepStablecoin, sepRole, sepMint.publicKey, holderATA, freezeAuthority)
+      ),
+      [authority]
+    );
+    let account = await getAccount(connection, holderATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(account.isFrozen).to.be.true;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildThawAccountIx(authority.publicKey, sepStablecoin, sepRole, sepMint.publicKey, holderATA, freezeAuthority)
+      ),
+      [authority]
+    );
+    account = await getAccount(connection, holderATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(account.isFrozen).to.be.false;
+
+    // The separate freeze PDA counts as controlled
+    const diverged = await simulateReturnData(
+      connection,
+      buildCheckAuthoritiesIx(sepStablecoin, sepMint.publicKey),
+      authority
+    );
+    expect(diverged?.[0] ?? 0).to.equal(0);
+  });
+
   it("transfers authority", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
 
     const ix = buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthority.publicKey);
     await sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
 
+    // Only the proposed authority can accept
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildAcceptAuthorityIx(authority.publicKey, stablecoinPDA)),
+        [authority]
+      ),
+      "NotPendingAuthority"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildAcceptAuthorityIx(newAuthority.publicKey, stablecoinPDA)),
+      [newAuthority]
+    );
+
+    // authority follows the discriminator; pending_authority sits after the
+    // strings, the five flag bytes, supply totals, freeze config, burn
+    // address, event mask and max_single_mint
+    const state = (await connection.getAccountInfo(stablecoinPDA))!.data;
+    expect(new PublicKey(state.subarray(8, 40)).equals(newAuthority.publicKey)).to.be.true;
+    let offset = 72;
+    for (let i = 0; i < 3; i++) offset += 4 + state.readUInt32LE(offset);
+    offset += 5 + 8 + 8 + 1 + 1 + 1 + 32 + 1 + 8;
+    expect(state[offset]).to.equal(0);
+
     // Transfer back for further tests
     const ix2 = buildTransferAuthorityIx(newAuthority.publicKey, stablecoinPDA, authority.publicKey);
     await sendAndConfirmTransaction(connection, new Transaction().add(ix2), [newAuthority]);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildAcceptAuthorityIx(authority.publicKey, stablecoinPDA)),
+      [authority]
+    );
+  });
+
+  it("enforces the authority transfer cooldown", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const cooldown = Buffer.alloc(8);
+    cooldown.writeBigInt64LE(BigInt(3));
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("set_authority_transfer_cooldown", authority.publicKey, stablecoinPDA, cooldown)
+      ),
+      [authority]
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthority.publicKey)),
+      [authority]
+    );
+    const accept = (signer: Keypair) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildAcceptAuthorityIx(signer.publicKey, stablecoinPDA)),
+        [signer]
+      );
+
+    await expectTxError(accept(newAuthority), "AuthorityTransferCooldown");
+
+    await new Promise((resolve) => setTimeout(resolve, 5_000));
+    await accept(newAuthority);
+
+    // Hand control back (the cooldown applies in this direction too)
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildTransferAuthorityIx(newAuthority.publicKey, stablecoinPDA, authority.publicKey)),
+      [newAuthority]
+    );
+    await new Promise((resolve) => setTimeout(resolve, 5_000));
+    await accept(authority);
+  });
+
+  it("freezes configuration during a pending authority transfer when enabled", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const send = (ix: TransactionInstruction) =>
+      sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+    const setCeiling = (amount: bigint) => {
+      const data = Buffer.alloc(8);
+      data.writeBigUInt64LE(amount);
+      return send(buildUpdateConfigIx("set_max_single_mint", authority.publicKey, stablecoinPDA, data));
+    };
+    const setFreeze = (enabled: boolean) =>
+      send(
+        buildUpdateConfigIx(
+          "set_freeze_config_during_transfer", authority.publicKey, stablecoinPDA, Buffer.from([enabled ? 1 : 0])
+        )
+      );
+
+    await setFreeze(true);
+    // No transfer pending: configuration stays open
+    await setCeiling(BigInt(0));
+
+    await send(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthority.publicKey));
+    await expectTxError(setCeiling(BigInt(5_000)), "ConfigFrozenDuringTransfer");
+    await expectTxError(setFreeze(false), "ConfigFrozenDuringTransfer");
+    // Role management is locked along with the config setters
+    const holder = Keypair.generate().publicKey;
+    await expectTxError(
+      send(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, findRolePDA(stablecoinPDA, holder)[0], holder, {
+          isMinter: false,
+          isBurner: true,
+          isPauser: false,
+          isBlacklister: false,
+          isSeizer: false,
+        })
+      ),
+      "ConfigFrozenDuringTransfer"
+    );
+
+    const sig = await send(buildCancelAuthorityTransferIx(authority.publicKey, stablecoinPDA));
+    expect(await fetchEvents(connection, sig, "AuthorityTransferCancelled")).to.have.length(1);
+    // The withdrawn proposal can no longer be accepted
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildAcceptAuthorityIx(newAuthority.publicKey, stablecoinPDA)),
+        [newAuthority]
+      ),
+      "NotPendingAuthority"
+    );
+
+    await setCeiling(BigInt(0));
+    await setFreeze(false);
+  });
+
+  it("accepts a longer URI when max_uri_len is raised at init", async () => {
+    const longUri = "https://example.com/metadata/" + "a".repeat(300);
+    const init = (maxUriLen?: number) => {
+      const mint = Keypair.generate();
+      const [stablecoin] = findStablecoinPDA(mint.publicKey);
+      const [role] = findRolePDA(stablecoin, authority.publicKey);
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildInitializeIx(authority.publicKey, stablecoin, mint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+            name: "Rich Metadata USD",
+            symbol: "RMUSD",
+            uri: longUri,
+            decimals: 6,
+            enablePermanentDelegate: false,
+            enableTransferHook: false,
+            defaultAccountFrozen: false,
+            maxUriLen,
+          })
+        ),
+        [authority, mint]
+      ).then(() => stablecoin);
+    };
+
+    await expectTxError(init(), "UriTooLong");
+    await expectTxError(init(513), "UriTooLong");
+
+    const stablecoin = await init(512);
+    const info = await connection.getAccountInfo(stablecoin);
+    // name + symbol + uri are Borsh strings after discriminator, authority and mint
+    let offset = 72;
+    for (let i = 0; i < 2; i++) offset += 4 + info!.data.readUInt32LE(offset);
+    const uriLen = info!.data.readUInt32LE(offset);
+    expect(info!.data.subarray(offset + 4, offset + 4 + uriLen).toString()).to.equal(longUri);
+  });
+
+  it("lets only metadata updaters change the name and URI", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const update = (updater: Keypair, role: PublicKey, name: string, uri: string) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateMetadataIx(updater.publicKey, stablecoinPDA, role, name, uri)),
+        [authority, updater]
+      );
+
+    await expectTxError(
+      update(minterKeypair, minterRole, "Minted USD", "https://example.com/minter.json"),
+      "Unauthorized"
+    );
+    await expectTxError(update(authority, authorityRole, "x".repeat(33), ""), "NameTooLong");
+    await expectTxError(update(authority, authorityRole, "Test USD", "a".repeat(201)), "UriTooLong");
+
+    const sig = await update(authority, authorityRole, "Test USD v2", "https://example.com/v2.json");
+    expect(await fetchEvents(connection, sig, "MetadataUpdated")).to.have.length(1);
+
+    const info = await connection.getAccountInfo(stablecoinPDA);
+    // name, symbol, uri are Borsh strings after discriminator, authority and mint
+    const strings: string[] = [];
+    let offset = 72;
+    for (let i = 0; i < 3; i++) {
+      const len = info!.data.readUInt32LE(offset);
+      strings.push(info!.data.subarray(offset + 4, offset + 4 + len).toString());
+      offset += 4 + len;
+    }
+    expect(strings).to.deep.equal(["Test USD v2", "TUSD", "https://example.com/v2.json"]);
+  });
+
+  it("records display_decimals at init and bounds it by decimals", async () => {
+    const init = (displayDecimals?: number) => {
+      const mint = Keypair.generate();
+      const [stablecoin] = findStablecoinPDA(mint.publicKey);
+      const [role] = findRolePDA(stablecoin, authority.publicKey);
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildInitializeIx(authority.publicKey, stablecoin, mint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+            name: "Display USD",
+            symbol: "DSPUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: false,
+            enableTransferHook: false,
+            defaultAccountFrozen: false,
+            displayDecimals,
+          })
+        ),
+        [authority, mint]
+      );
+    };
+
+    await expectTxError(init(7), "InvalidDisplayDecimals");
+
+    const sig = await init(2);
+    const events = await fetchEvents(connection, sig, "StablecoinInitialized");
+    expect(events).to.have.length(1);
+    // stablecoin, mint, authority (96) + name + symbol, then is_sss2, decimals, display_decimals
+    let offset = 96;
+    for (let i = 0; i < 2; i++) offset += 4 + events[0].readUInt32LE(offset);
+    expect(events[0][offset + 1]).to.equal(6);
+    expect(events[0][offset + 2]).to.equal(2);
+  });
+
+  it("closes a paused stablecoin with no supply and refunds its rent", async () => {
+    const closingMint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(closingMint.publicKey);
+    const [role] = findRolePDA(stablecoin, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoin, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoin, closingMint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+          name: "Sunset USD",
+          symbol: "SUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, closingMint]
+    );
+    const holding = await createTokenAccount(connection, authority, closingMint.publicKey, authority.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoin, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(authority.publicKey, stablecoin, role, minterInfo, closingMint.publicKey, holding, BigInt(100))
+      ),
+      [authority]
+    );
+
+    const close = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildCloseStablecoinIx(authority.publicKey, stablecoin, closingMint.publicKey)),
+        [authority]
+      );
+    const send = (ix: ReturnType<typeof buildPauseIx>) =>
+      sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+
+    await expectTxError(close(), "NotPaused");
+    await send(buildPauseIx(authority.publicKey, stablecoin, role));
+    await expectTxError(close(), "SupplyNotZero");
+
+    await send(buildUnpauseIx(authority.publicKey, stablecoin, role));
+    await send(buildBurnTokensIx(authority.publicKey, stablecoin, role, closingMint.publicKey, holding, BigInt(100)));
+    await send(buildPauseIx(authority.publicKey, stablecoin, role));
+
+    const sig = await close();
+    expect(await fetchEvents(connection, sig, "StablecoinClosed")).to.have.length(1);
+    expect(await connection.getAccountInfo(stablecoin)).to.be.null;
+    expect(await connection.getAccountInfo(closingMint.publicKey)).to.be.null;
+  });
+
+  it("emits MassFreezeAlert when frozen accounts rise above max_frozen_alert", async () => {
+    const alertMint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(alertMint.publicKey);
+    const [role] = findRolePDA(stablecoin, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoin, alertMint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+          name: "Alert USD",
+          symbol: "ALRTUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, alertMint]
+    );
+    const threshold = Buffer.alloc(8);
+    threshold.writeBigUInt64LE(BigInt(2));
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildUpdateConfigIx("set_max_frozen_alert", authority.publicKey, stablecoin, threshold)),
+      [authority]
+    );
+
+    const accounts: PublicKey[] = [];
+    for (let i = 0; i < 4; i++) {
+      accounts.push(await createTokenAccount(connection, authority, alertMint.publicKey, Keypair.generate().publicKey));
+    }
+    const send = (ix: ReturnType<typeof buildFreezeAccountIx>) =>
+      sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+    const freeze = async (account: PublicKey) => {
+      const sig = await send(buildFreezeAccountIx(authority.publicKey, stablecoin, role, alertMint.publicKey, account));
+      return fetchEvents(connection, sig, "MassFreezeAlert");
+    };
+
+    expect(await freeze(accounts[0])).to.have.length(0);
+    expect(await freeze(accounts[1])).to.have.length(0);
+
+    // MassFreezeAlert { stablecoin, frozen_count, threshold, frozen_by, timestamp }
+    const [alert] = await freeze(accounts[2]);
+    expect(new PublicKey(alert.subarray(0, 32)).equals(stablecoin)).to.be.true;
+    expect(alert.readBigUInt64LE(32)).to.equal(BigInt(3));
+    expect(alert.readBigUInt64LE(40)).to.equal(BigInt(2));
+    expect(new PublicKey(alert.subarray(48, 80)).equals(authority.publicKey)).to.be.true;
+
+    // Thawing brings the count back under the threshold, so crossing it again re-alerts
+    await send(buildThawAccountIx(authority.publicKey, stablecoin, role, alertMint.publicKey, accounts[0]));
+    expect(await freeze(accounts[3])).to.have.length(1);
+  });
+
+  it("reports circulating supply as minted minus burned", async () => {
+    const supplyMint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(supplyMint.publicKey);
+    const [role] = findRolePDA(stablecoin, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoin, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoin, supplyMint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+          name: "Supply USD",
+          symbol: "SPLYUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, supplyMint]
+    );
+    expect(await fetchCirculatingSupply(connection, stablecoin, authority)).to.equal(BigInt(0));
+
+    const holding = await createTokenAccount(connection, authority, supplyMint.publicKey, authority.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoin, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(authority.publicKey, stablecoin, role, minterInfo, supplyMint.publicKey, holding, BigInt(300)),
+        buildBurnTokensIx(authority.publicKey, stablecoin, role, supplyMint.publicKey, holding, BigInt(120))
+      ),
+      [authority]
+    );
+    expect(await fetchCirculatingSupply(connection, stablecoin, authority)).to.equal(BigInt(180));
+  });
+
+  it("re-emits the current configuration as a snapshot", async () => {
+    const snapshotMint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(snapshotMint.publicKey);
+    const [role] = findRolePDA(stablecoin, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoin, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoin, snapshotMint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+          name: "Snapshot USD",
+          symbol: "SNAPUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+          displayDecimals: 2,
+        })
+      ),
+      [authority, snapshotMint]
+    );
+    const holding = await createTokenAccount(connection, authority, snapshotMint.publicKey, authority.publicKey);
+    const alertThreshold = Buffer.alloc(8);
+    alertThreshold.writeBigUInt64LE(BigInt(5));
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoin, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(authority.publicKey, stablecoin, role, minterInfo, snapshotMint.publicKey, holding, BigInt(250)),
+        buildUpdateConfigIx("set_allow_burn_when_paused", authority.publicKey, stablecoin, Buffer.from([1])),
+        buildUpdateConfigIx("set_max_frozen_alert", authority.publicKey, stablecoin, alertThreshold),
+        buildPauseIx(authority.publicKey, stablecoin, role)
+      ),
+      [authority]
+    );
+
+    // Permissionless: any payer can request a snapshot.
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildEmitConfigSnapshotIx(stablecoin)),
+      [recipientKeypair]
+    );
+    const events = await fetchEvents(connection, sig, "ConfigSnapshot");
+    expect(events).to.have.length(1);
+    const data = events[0];
+    expect(new PublicKey(data.subarray(0, 32)).equals(stablecoin)).to.be.true;
+    expect(new PublicKey(data.subarray(32, 64)).equals(authority.publicKey)).to.be.true;
+    expect(new PublicKey(data.subarray(64, 96)).equals(snapshotMint.publicKey)).to.be.true;
+    // name, symbol, uri, then decimals, display_decimals, three feature flags, paused
+    let offset = 96;
+    const strings: string[] = [];
+    for (let i = 0; i < 3; i++) {
+      const len = data.readUInt32LE(offset);
+      strings.push(data.subarray(offset + 4, offset + 4 + len).toString());
+      offset += 4 + len;
+    }
+    expect(strings).to.deep.equal(["Snapshot USD", "SNAPUSD", ""]);
+    expect(data[offset]).to.equal(6);
+    expect(data[offset + 1]).to.equal(2);
+    expect(data[offset + 5]).to.equal(1);
+    expect(data.readBigUInt64LE(offset + 6)).to.equal(BigInt(250));
+    expect(data.readBigUInt64LE(offset + 14)).to.equal(BigInt(0));
+    // With every Option unset and every Vec empty, the remaining settings sit
+    // at fixed offsets: allow_burn_when_paused, max_frozen_alert, then the
+    // timestamp closing the event.
+    expect(data[offset + 150]).to.equal(1);
+    expect(data.readBigUInt64LE(offset + 172)).to.equal(BigInt(5));
+    expect(data.length).to.equal(offset + 189);
+  });
+
+  it("leaves minter, burner and seizer unassigned when init_grant_all_roles is false", async () => {
+    const provisionedMint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(provisionedMint.publicKey);
+    const [role] = findRolePDA(stablecoin, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoin, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoin, provisionedMint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+          name: "Provisioned USD",
+          symbol: "PRVUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+          initGrantAllRoles: false,
+        })
+      ),
+      [authority, provisionedMint]
+    );
+
+    // role account: disc(8) + stablecoin(32) + holder(32), then the five flags
+    const roleInfo = await connection.getAccountInfo(role);
+    expect([...roleInfo!.data.subarray(72, 77)]).to.deep.equal([0, 0, 1, 1, 0]);
+
+    const holding = await createTokenAccount(connection, authority, provisionedMint.publicKey, authority.publicKey);
+    const mintIx = (amount: number) =>
+      buildMintTokensIx(authority.publicKey, stablecoin, role, minterInfo, provisionedMint.publicKey, holding, BigInt(amount));
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildUpdateMinterIx(authority.publicKey, stablecoin, minterInfo, authority.publicKey, BigInt(1_000))),
+      [authority]
+    );
+    await expectTxError(
+      sendAndConfirmTransaction(connection, new Transaction().add(mintIx(100)), [authority]),
+      "Unauthorized"
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoin, role, authority.publicKey, {
+          isMinter: true,
+          isBurner: false,
+          isPauser: true,
+          isBlacklister: true,
+          isSeizer: false,
+        }),
+        mintIx(101)
+      ),
+      [authority]
+    );
+    const account = await getAccount(connection, holding, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(account.amount).to.equal(BigInt(101));
+  });
+
+  it("rejects a reused symbol once the issuer registry exists", async () => {
+    const issuer = Keypair.generate();
+    const airdrop = await connection.requestAirdrop(issuer.publicKey, 10 * LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    const init = (symbol: string) => {
+      const mint = Keypair.generate();
+      const [stablecoin] = findStablecoinPDA(mint.publicKey);
+      const [role] = findRolePDA(stablecoin, issuer.publicKey);
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildInitializeIx(
+            issuer.publicKey,
+            stablecoin,
+            mint.publicKey,
+            role,
+            SSS_HOOK_PROGRAM_ID,
+            {
+              name: "Registry USD",
+              symbol,
+              uri: "",
+              decimals: 6,
+              enablePermanentDelegate: false,
+              enableTransferHook: false,
+              defaultAccountFrozen: false,
+            }
+          )
+        ),
+        [issuer, mint]
+      );
+    };
+
+    // Before the registry exists symbols are not tracked
+    await init("RUSD");
+    await init("RUSD");
+
+    await sendAndConfirmTransaction(connection, new Transaction().add(buildInitIssuerRegistryIx(issuer.publicKey)), [issuer]);
+
+    await init("RUSD");
+    await expectTxError(init("RUSD"), "SymbolAlreadyUsed");
+    await expectTxError(init("rusd"), "SymbolAlreadyUsed");
+    await init("RUSD2");
+
+    // Another authority's registry cannot stand in for the issuer's own
+    const mint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(mint.publicKey);
+    const ix = buildInitializeIx(
+      issuer.publicKey,
+      stablecoin,
+      mint.publicKey,
+      findRolePDA(stablecoin, issuer.publicKey)[0],
+      SSS_HOOK_PROGRAM_ID,
+      { name: "Registry USD", symbol: "RUSD", uri: "", decimals: 6, enablePermanentDelegate: false, enableTransferHook: false, defaultAccountFrozen: false }
+    );
+    ix.keys[4].pubkey = findIssuerRegistryPDA(authority.publicKey)[0];
+    await expectTxError(sendAndConfirmTransaction(connection, new Transaction().add(ix), [issuer, mint]), "ConstraintSeeds");
+  });
+
+  it("rejects mints beyond the declared reserve ratio", async () => {
+    // Preloaded by the test validator with 1_000_000 base units of reserves
+    const reserveOracle = new PublicKey("33UxWUB8EebT5nduqxkk2qe8LMNqHDhL14NVkdKc1hWW");
+    const reserveMint = Keypair.generate();
+    const [stablecoin] = findStablecoinPDA(reserveMint.publicKey);
+    const [role] = findRolePDA(stablecoin, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoin, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoin,
+          reserveMint.publicKey,
+          role,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Backed USD",
+            symbol: "BUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: false,
+            enableTransferHook: false,
+            defaultAccountFrozen: false,
+          }
+        )
+      ),
+      [authority, reserveMint]
+    );
+    const recipientATA = await createTokenAccount(connection, authority, reserveMint.publicKey, authority.publicKey);
+
+    // An oracle with a zero ratio would let every mint through
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx(
+            "set_reserve_oracle", authority.publicKey, stablecoin,
+            Buffer.concat([Buffer.from([1]), reserveOracle.toBuffer(), Buffer.alloc(2)])
+          )
+        ),
+        [authority]
+      ),
+      "InvalidReserveRatio"
+    );
+
+    // Half-backed: 1_000_000 of reserves cover up to 2_000_000 of supply
+    const ratio = Buffer.alloc(2);
+    ratio.writeUInt16LE(5_000);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx(
+          "set_reserve_oracle", authority.publicKey, stablecoin,
+          Buffer.concat([Buffer.from([1]), reserveOracle.toBuffer(), ratio])
+        ),
+        buildUpdateMinterIx(authority.publicKey, stablecoin, minterInfo, authority.publicKey, BigInt(10_000_000))
+      ),
+      [authority]
+    );
+
+    const mint = (amount: bigint, withOracle = true) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(
+            authority.publicKey, stablecoin, role, minterInfo, reserveMint.publicKey, recipientATA, amount,
+            withOracle ? [{ pubkey: reserveOracle, isSigner: false, isWritable: false }] : []
+          )
+        ),
+        [authority]
+      );
+
+    await expectTxError(mint(BigInt(1_000), false), "ReserveOracleMissing");
+    await mint(BigInt(2_000_000));
+    await expectTxError(mint(BigInt(1)), "ReserveRatioBreached");
+
+    const supply = (await getMint(connection, reserveMint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID)).supply;
+    expect(supply).to.equal(BigInt(2_000_000));
+  });
+
+  describe("recovery authority", () => {
+    const recovery = Keypair.generate();
+
+    const initRecoverable = async (inactivityPeriod: number) => {
+      const mint = Keypair.generate();
+      const [stablecoin] = findStablecoinPDA(mint.publicKey);
+      const [role] = findRolePDA(stablecoin, authority.publicKey);
+      const period = Buffer.alloc(8);
+      period.writeBigInt64LE(BigInt(inactivityPeriod));
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildInitializeIx(authority.publicKey, stablecoin, mint.publicKey, role, SSS_HOOK_PROGRAM_ID, {
+            name: "Recoverable USD",
+            symbol: "RCUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: false,
+            enableTransferHook: false,
+            defaultAccountFrozen: false,
+          }),
+          buildUpdateConfigIx(
+            "set_recovery_authority", authority.publicKey, stablecoin,
+            Buffer.concat([Buffer.from([1]), recovery.publicKey.toBuffer(), period])
+          )
+        ),
+        [authority, mint]
+      );
+      return stablecoin;
+    };
+
+    const claim = (stablecoin: PublicKey) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildClaimRecoveryIx(recovery.publicKey, stablecoin)),
+        [recovery]
+      );
+
+    before(async () => {
+      const airdrop = await connection.requestAirdrop(recovery.publicKey, LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(airdrop);
+    });
+
+    it("lets the recovery authority claim control after the inactivity period", async () => {
+      const stablecoin = await initRecoverable(2);
+      await new Promise((resolve) => setTimeout(resolve, 4_000));
+      await claim(stablecoin);
+
+      // The old key is locked out of authority-gated instructions
+      const setMask = (signer: Keypair) =>
+        sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(buildUpdateConfigIx("set_event_mask", signer.publicKey, stablecoin, Buffer.from([0]))),
+          [signer]
+        );
+      await expectTxError(setMask(authority), "ConstraintRaw");
+      await setMask(recovery);
+    });
+
+    it("blocks the claim while the authority is active", async () => {
+      const stablecoin = await initRecoverable(60);
+      await expectTxError(claim(stablecoin), "AuthorityStillActive");
+
+      // Any configuration change counts as activity and restarts the timer
+      const stale = await initRecoverable(5);
+      await new Promise((resolve) => setTimeout(resolve, 3_000));
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateConfigIx("authority_heartbeat", authority.publicKey, stale, Buffer.alloc(0))),
+        [authority]
+      );
+      await new Promise((resolve) => setTimeout(resolve, 3_000));
+      await expectTxError(claim(stale), "AuthorityStillActive");
+    });
+
+    it("counts role and minter management as authority activity", async () => {
+      const stablecoin = await initRecoverable(5);
+      const holder = Keypair.generate().publicKey;
+      const [holderRole] = findRolePDA(stablecoin, holder);
+      await new Promise((resolve) => setTimeout(resolve, 3_000));
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateRolesIx(authority.publicKey, stablecoin, holderRole, holder, {
+            isMinter: false,
+            isBurner: true,
+            isPauser: false,
+            isBlacklister: false,
+            isSeizer: false,
+          })
+        ),
+        [authority]
+      );
+      await new Promise((resolve) => setTimeout(resolve, 3_000));
+      await expectTxError(claim(stablecoin), "AuthorityStillActive");
+    });
+
+    it("counts role-gated instructions the authority signs as activity", async () => {
+      const stablecoin = await initRecoverable(5);
+      const [role] = findRolePDA(stablecoin, authority.publicKey);
+      await new Promise((resolve) => setTimeout(resolve, 3_000));
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildPauseIx(authority.publicKey, stablecoin, role)),
+        [authority]
+      );
+      await new Promise((resolve) => setTimeout(resolve, 3_000));
+      await expectTxError(claim(stablecoin), "AuthorityStillActive");
+    });
   });
 });
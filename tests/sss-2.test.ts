@@ -1,26 +1,59 @@
 import {
+  ComputeBudgetProgram,
   Connection,
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SystemProgram,
   sendAndConfirmTransaction,
   Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
+import * as crypto from "crypto";
 import { expect } from "chai";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  addExtraAccountMetasForExecute,
+  createTransferCheckedInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getAccount,
+  getAssociatedTokenAddress,
+} from "@solana/spl-token";
 import {
   SSS_TOKEN_PROGRAM_ID,
   SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   findStablecoinPDA,
   findRolePDA,
   findMinterPDA,
   findBlacklistPDA,
+  blacklistEntryFilter,
+  findMintLockPDA,
   buildInitializeIx,
   buildUpdateRolesIx,
   buildUpdateMinterIx,
   buildMintTokensIx,
   buildAddToBlacklistIx,
+  buildImportBlacklistIx,
+  buildTryRemoveFromBlacklistIx,
+  BLACKLIST_REASON_CODES,
+  buildLockdownIx,
+  buildRemoveFromBlacklistIx,
+  buildUpdateExtraAccountMetaListIx,
+  findExtraAccountMetaListPDA,
+  buildReassignBlacklistOwnerIx,
+  buildMigrateBlacklistEntryIx,
   buildSeizeIx,
   buildThawAccountIx,
+  buildUpdateConfigIx,
+  buildInitializeExtraAccountMetaListIx,
   createTokenAccount,
+  expectTxError,
+  fetchReturnData,
+  fetchBlacklistEntry,
+  fetchComplianceStats,
+  fetchEvents,
 } from "./helpers";
 
 describe("SSS-2: Compliant Stablecoin Lifecycle", () => {
@@ -168,7 +201,9 @@ describe("SSS-2: Compliant Stablecoin Lifecycle", () => {
       blRole,
       blacklistEntry,
       badActorKeypair.publicKey,
-      "Sanctions list match"
+      "Sanctions list match",
+      undefined,
+      BLACKLIST_REASON_CODES.sanctions
     );
 
     await sendAndConfirmTransaction(connection, new Transaction().add(ix), [blacklisterKeypair]);
@@ -177,6 +212,293 @@ describe("SSS-2: Compliant Stablecoin Lifecycle", () => {
     const info = await connection.getAccountInfo(blacklistEntry);
     expect(info).to.not.be.null;
     expect(info!.data.length).to.be.greaterThan(0);
+    expect((await fetchBlacklistEntry(connection, blacklistEntry)).reasonCode).to.equal(
+      BLACKLIST_REASON_CODES.sanctions
+    );
+
+    // A repeat add is refused and leaves the original record alone
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildAddToBlacklistIx(
+            blacklisterKeypair.publicKey,
+            stablecoinPDA,
+            blRole,
+            blacklistEntry,
+            badActorKeypair.publicKey,
+            "Duplicate report"
+          )
+        ),
+        [blacklisterKeypair]
+      ),
+      "AlreadyBlacklisted"
+    );
+    const after = await connection.getAccountInfo(blacklistEntry);
+    expect(after!.data.equals(info!.data)).to.be.true;
+  });
+
+  it("reassigns a blacklist entry's owner and keeps its timestamp", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [entry] = findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey);
+    const successor = Keypair.generate().publicKey;
+    const before = await fetchBlacklistEntry(connection, entry);
+
+    // Only the master authority may reassign
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildReassignBlacklistOwnerIx(blacklisterKeypair.publicKey, stablecoinPDA, entry, successor)),
+        [blacklisterKeypair]
+      ),
+      "ConstraintRaw"
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildReassignBlacklistOwnerIx(authority.publicKey, stablecoinPDA, entry, successor)),
+      [authority]
+    );
+
+    const after = await fetchBlacklistEntry(connection, entry);
+    expect(before.blacklistedBy.equals(blacklisterKeypair.publicKey)).to.be.true;
+    expect(after.blacklistedBy.equals(successor)).to.be.true;
+    expect(after.blacklistedAt).to.equal(before.blacklistedAt);
+    expect(after.reason).to.equal(before.reason);
+  });
+
+  it("migrate_blacklist_entry keeps current-layout entries intact", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [entry] = findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey);
+    const before = (await connection.getAccountInfo(entry))!;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMigrateBlacklistEntryIx(authority.publicKey, stablecoinPDA, entry)),
+      [authority]
+    );
+
+    const after = (await connection.getAccountInfo(entry))!;
+    expect(after.data.equals(before.data)).to.be.true;
+
+    // Only blacklist PDAs of this stablecoin can be migrated
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMigrateBlacklistEntryIx(authority.publicKey, stablecoinPDA, blRole)),
+        [authority]
+      ),
+      "AccountDidNotDeserialize"
+    );
+  });
+
+  it("imports a sanctions delta, skipping addresses already blacklisted", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    const fresh = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    const [existingEntry] = findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey);
+    const existingBefore = await connection.getAccountInfo(existingEntry);
+
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildImportBlacklistIx(
+          blacklisterKeypair.publicKey,
+          stablecoinPDA,
+          blRole,
+          [fresh[0], badActorKeypair.publicKey, fresh[1]],
+          "OFAC delta",
+          BLACKLIST_REASON_CODES.sanctions
+        )
+      ),
+      [blacklisterKeypair]
+    );
+
+    // ImportBlacklistResult { added: u32, skipped: u32 }
+    const result = await fetchReturnData(connection, sig);
+    expect(result!.readUInt32LE(0)).to.equal(2);
+    expect(result!.readUInt32LE(4)).to.equal(1);
+
+    for (const address of fresh) {
+      const info = await connection.getAccountInfo(findBlacklistPDA(stablecoinPDA, address)[0]);
+      expect(info).to.not.be.null;
+      expect(info!.owner.equals(SSS_TOKEN_PROGRAM_ID)).to.be.true;
+      const entry = await fetchBlacklistEntry(connection, findBlacklistPDA(stablecoinPDA, address)[0]);
+      expect(entry.reasonCode).to.equal(BLACKLIST_REASON_CODES.sanctions);
+    }
+    // The pre-existing entry keeps its original reason and timestamp
+    const existingAfter = await connection.getAccountInfo(existingEntry);
+    expect(existingAfter!.data.equals(existingBefore!.data)).to.be.true;
+  });
+
+  it("unblacklists idempotently with try_remove_from_blacklist", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    const listed = Keypair.generate().publicKey;
+    const neverListed = Keypair.generate().publicKey;
+    const [listedEntry] = findBlacklistPDA(stablecoinPDA, listed);
+    const tryRemove = async (...addresses: PublicKey[]) => {
+      const sig = await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          ...addresses.map((address) =>
+            buildTryRemoveFromBlacklistIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, address)
+          )
+        ),
+        [blacklisterKeypair]
+      );
+      // Return data of the last instruction; `false` is trimmed to nothing
+      return (await fetchReturnData(connection, sig))?.[0] ?? 0;
+    };
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildAddToBlacklistIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, listedEntry, listed, "Cleared later")
+      ),
+      [blacklisterKeypair]
+    );
+
+    // The strict variant cannot get past the missing account
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildRemoveFromBlacklistIx(
+            blacklisterKeypair.publicKey,
+            stablecoinPDA,
+            blRole,
+            findBlacklistPDA(stablecoinPDA, neverListed)[0],
+            neverListed
+          )
+        ),
+        [blacklisterKeypair]
+      ),
+      "AccountNotInitialized"
+    );
+
+    // A missing entry does not abort the rest of the batch
+    expect(await tryRemove(neverListed, listed)).to.equal(1);
+    expect(await connection.getAccountInfo(listedEntry)).to.be.null;
+    expect(await tryRemove(listed)).to.equal(0);
+  });
+
+  it("lists a stablecoin's blacklist entries with a memcmp filter", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const accounts = await connection.getProgramAccounts(SSS_TOKEN_PROGRAM_ID, {
+      filters: [blacklistEntryFilter(stablecoinPDA)],
+    });
+
+    const addresses = accounts.map(({ pubkey, account }) => {
+      // BlacklistEntry: disc(8) + stablecoin(32) + address(32)
+      expect(new PublicKey(account.data.subarray(8, 40)).equals(stablecoinPDA)).to.be.true;
+      const address = new PublicKey(account.data.subarray(40, 72));
+      expect(pubkey.equals(findBlacklistPDA(stablecoinPDA, address)[0])).to.be.true;
+      return address.toBase58();
+    });
+    expect(addresses).to.include(badActorKeypair.publicKey.toBase58());
+    // Role and minter PDAs also start with the stablecoin key but are excluded
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    expect(accounts.some(({ pubkey }) => pubkey.equals(blRole))).to.be.false;
+  });
+
+  it("accepts a blacklist import at MAX_BATCH_BLACKLIST and rejects one past it", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    const MAX_BATCH_BLACKLIST = 10;
+    const importBatch = (size: number) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+          buildImportBlacklistIx(
+            blacklisterKeypair.publicKey,
+            stablecoinPDA,
+            blRole,
+            Array.from({ length: size }, () => Keypair.generate().publicKey),
+            "Bulk"
+          )
+        ),
+        [blacklisterKeypair]
+      );
+
+    const result = await fetchReturnData(connection, await importBatch(MAX_BATCH_BLACKLIST));
+    expect(result!.readUInt32LE(0)).to.equal(MAX_BATCH_BLACKLIST);
+    await expectTxError(importBatch(MAX_BATCH_BLACKLIST + 1), "BatchTooLarge");
+  });
+
+  it("blacklists and freezes in one lockdown", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    const suspect = Keypair.generate().publicKey;
+    const suspectATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, suspect);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, suspectATA)
+      ),
+      [authority]
+    );
+
+    // Blacklisting alone is not enough to freeze
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildLockdownIx(
+            blacklisterKeypair.publicKey, stablecoinPDA, blRole, suspect, mintKeypair.publicKey, suspectATA, "Incident 42"
+          )
+        ),
+        [blacklisterKeypair]
+      ),
+      "Unauthorized"
+    );
+
+    // A lapsed temporary entry does not block the lockdown, which overwrites
+    // it without counting the address twice
+    const suspectEntry = findBlacklistPDA(stablecoinPDA, suspect)[0];
+    const clock = async () => BigInt((await connection.getBlockTime(await connection.getSlot("confirmed")))!);
+    const expiresAt = (await clock()) + BigInt(2);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildAddToBlacklistIx(
+          authority.publicKey, stablecoinPDA, authorityRole, suspectEntry, suspect, "Temporary hold", expiresAt
+        )
+      ),
+      [authority]
+    );
+    const before = await fetchComplianceStats(connection, stablecoinPDA, authority);
+    while ((await clock()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    const lockdown = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildLockdownIx(
+            authority.publicKey, stablecoinPDA, authorityRole, suspect, mintKeypair.publicKey, suspectATA, "Incident 42",
+            stablecoinPDA, BLACKLIST_REASON_CODES.fraud
+          )
+        ),
+        [authority]
+      );
+    await lockdown();
+
+    const entry = await fetchBlacklistEntry(connection, suspectEntry);
+    expect(entry.address.equals(suspect)).to.be.true;
+    expect(entry.reason).to.equal("Incident 42");
+    expect(entry.reasonCode).to.equal(BLACKLIST_REASON_CODES.fraud);
+    const account = await getAccount(connection, suspectATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(account.isFrozen).to.be.true;
+    const after = await fetchComplianceStats(connection, stablecoinPDA, authority);
+    expect(after.blacklistEntries).to.equal(before.blacklistEntries);
+
+    // The entry is live now, so a second lockdown is refused
+    await expectTxError(lockdown(), "AlreadyBlacklisted");
   });
 
   it("seizes tokens from bad actor account", async () => {
@@ -217,9 +539,15 @@ describe("SSS-2: Compliant Stablecoin Lifecycle", () => {
       szRole,
       mintKeypair.publicKey,
       badActorATA,
+      badActorKeypair.publicKey,
       treasuryATA
     );
-    await sendAndConfirmTransaction(connection, new Transaction().add(seizeIx), [seizerKeypair]);
+    const sig = await sendAndConfirmTransaction(connection, new Transaction().add(seizeIx), [seizerKeypair]);
+
+    // The seized amount comes back as return data, which is what a CPI caller
+    // reads with get_return_data (trailing zero bytes are trimmed)
+    const returned = await fetchReturnData(connection, sig);
+    expect(Buffer.concat([returned!, Buffer.alloc(8)]).readBigUInt64LE(0)).to.equal(BigInt(500_000));
 
     // Verify bad actor balance is 0
     const badActorBalance = await connection.getTokenAccountBalance(badActorATA);
@@ -229,4 +557,1361 @@ describe("SSS-2: Compliant Stablecoin Lifecycle", () => {
     const treasuryBalance = await connection.getTokenAccountBalance(treasuryATA);
     expect(Number(treasuryBalance.value.amount)).to.be.greaterThan(0);
   });
+
+  it("routes a configured seizure fee to the fee treasury", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const thaw = (account: typeof szRole) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, account)
+        ),
+        [authority]
+      );
+
+    const targetOwner = Keypair.generate().publicKey;
+    const target = await createTokenAccount(connection, authority, mintKeypair.publicKey, targetOwner);
+    const feeTreasury = await createTokenAccount(connection, authority, mintKeypair.publicKey, seizerKeypair.publicKey);
+    await thaw(target);
+    await thaw(feeTreasury);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildMintTokensIx(
+          minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, target, BigInt(100_000)
+        )
+      ),
+      [minterKeypair]
+    );
+    const treasuryATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const treasuryBefore = BigInt((await connection.getTokenAccountBalance(treasuryATA)).value.amount);
+
+    const setSeizeFee = (bps: number, treasury = feeTreasury) => {
+      const data = Buffer.alloc(2);
+      data.writeUInt16LE(bps);
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateConfigIx("set_seize_fee", authority.publicKey, stablecoinPDA, Buffer.concat([data, treasury.toBuffer()]))
+        ),
+        [authority]
+      );
+    };
+    await expectTxError(setSeizeFee(1_001), "InvalidFeeBps");
+    await expectTxError(setSeizeFee(250, PublicKey.default), "FeeTreasuryNotSet");
+    await setSeizeFee(250); // 2.5%
+
+    const seize = (withFeeTreasury: boolean) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSeizeIx(
+            seizerKeypair.publicKey, stablecoinPDA, szRole, mintKeypair.publicKey, target, targetOwner, treasuryATA,
+            withFeeTreasury ? feeTreasury : undefined
+          )
+        ),
+        [seizerKeypair]
+      );
+    await expectTxError(seize(false), "FeeTreasuryMissing");
+    await seize(true);
+
+    const treasuryAfter = BigInt((await connection.getTokenAccountBalance(treasuryATA)).value.amount);
+    expect(treasuryAfter - treasuryBefore).to.equal(BigInt(97_500));
+    expect((await connection.getTokenAccountBalance(feeTreasury)).value.amount).to.equal("2500");
+    expect((await connection.getTokenAccountBalance(target)).value.amount).to.equal("0");
+
+    await setSeizeFee(0);
+  });
+
+  it("caps the amount a single seizure may move", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+
+    const targetOwner = Keypair.generate().publicKey;
+    const target = await createTokenAccount(connection, authority, mintKeypair.publicKey, targetOwner);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, target),
+        buildMintTokensIx(
+          minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, target, BigInt(100_000)
+        )
+      ),
+      [authority, minterKeypair]
+    );
+    const treasuryATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+
+    const setCap = (cap: bigint) => {
+      const data = Buffer.alloc(8);
+      data.writeBigUInt64LE(cap);
+      return sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateConfigIx("set_max_seize_per_tx", authority.publicKey, stablecoinPDA, data)),
+        [authority]
+      );
+    };
+    const seize = () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSeizeIx(
+            seizerKeypair.publicKey, stablecoinPDA, szRole, mintKeypair.publicKey, target, targetOwner, treasuryATA
+          )
+        ),
+        [seizerKeypair]
+      );
+
+    await setCap(BigInt(99_999));
+    await expectTxError(seize(), "SeizeAmountTooLarge");
+
+    await setCap(BigInt(100_000));
+    await seize();
+    expect((await connection.getTokenAccountBalance(target)).value.amount).to.equal("0");
+
+    await setCap(BigInt(0));
+  });
+
+  it("seizes only the requested amount with seize_amount", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+
+    const targetOwner = Keypair.generate().publicKey;
+    const target = await createTokenAccount(connection, authority, mintKeypair.publicKey, targetOwner);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, target),
+        buildMintTokensIx(
+          minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, target, BigInt(100_000)
+        )
+      ),
+      [authority, minterKeypair]
+    );
+    const treasuryATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+
+    const seize = (amount: bigint) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSeizeIx(
+            seizerKeypair.publicKey, stablecoinPDA, szRole, mintKeypair.publicKey, target, targetOwner, treasuryATA,
+            undefined, amount
+          )
+        ),
+        [seizerKeypair]
+      );
+
+    await expectTxError(seize(BigInt(0)), "ZeroAmount");
+    await expectTxError(seize(BigInt(100_001)), "SeizeAmountExceedsBalance");
+
+    const sig = await seize(BigInt(40_000));
+    const events = await fetchEvents(connection, sig, "TokensSeized");
+    expect(events).to.have.length(1);
+    // stablecoin (32) + from (32) + to (32) + amount (8)
+    expect(events[0].readBigUInt64LE(96)).to.equal(BigInt(40_000));
+    expect((await connection.getTokenAccountBalance(target)).value.amount).to.equal("60000");
+  });
+
+  it("reports compliance stats that track blacklisting and seizures", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+    const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const suspect = Keypair.generate().publicKey;
+    const suspectATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, suspect);
+    const treasuryATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const before = await fetchComplianceStats(connection, stablecoinPDA, authority);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, suspectATA),
+        buildMintTokensIx(
+          minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, suspectATA, BigInt(7_000)
+        ),
+        buildAddToBlacklistIx(
+          blacklisterKeypair.publicKey, stablecoinPDA, blRole, findBlacklistPDA(stablecoinPDA, suspect)[0], suspect, "Fraud"
+        )
+      ),
+      [authority, minterKeypair, blacklisterKeypair]
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildSeizeIx(
+          seizerKeypair.publicKey, stablecoinPDA, szRole, mintKeypair.publicKey, suspectATA, suspect, treasuryATA
+        )
+      ),
+      [seizerKeypair]
+    );
+
+    const after = await fetchComplianceStats(connection, stablecoinPDA, authority);
+    expect(after.blacklistEntries - before.blacklistEntries).to.equal(BigInt(1));
+    expect(after.seizureCount - before.seizureCount).to.equal(BigInt(1));
+    expect(after.totalSeized - before.totalSeized).to.equal(BigInt(7_000));
+  });
+
+  it("rejects seizing from an account not owned by Token-2022", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+    const treasuryATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey,
+      authority.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    // A plain wallet (system-owned) is not a token account
+    const seizeIx = buildSeizeIx(
+      seizerKeypair.publicKey,
+      stablecoinPDA,
+      szRole,
+      mintKeypair.publicKey,
+      userKeypair.publicKey,
+      userKeypair.publicKey,
+      treasuryATA
+    );
+    await expectTxError(
+      sendAndConfirmTransaction(connection, new Transaction().add(seizeIx), [seizerKeypair]),
+      "InvalidTokenAccount"
+    );
+  });
+
+  it("rejects a mint bundled with a transfer to a blacklisted account", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const [badActorEntry] = findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey);
+    const userATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, userKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const badActorATA = await getAssociatedTokenAddress(
+      mintKeypair.publicKey, badActorKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("set_mint_introspection", authority.publicKey, stablecoinPDA, Buffer.from([1]))
+      ),
+      [authority]
+    );
+
+    const mintIx = buildMintTokensIx(
+      minterKeypair.publicKey,
+      stablecoinPDA,
+      minterRole,
+      minterInfo,
+      mintKeypair.publicKey,
+      userATA,
+      BigInt(1_000),
+      [
+        { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: badActorATA, isSigner: false, isWritable: false },
+        { pubkey: badActorEntry, isSigner: false, isWritable: false },
+      ]
+    );
+    const transferIx = createTransferCheckedInstruction(
+      userATA,
+      mintKeypair.publicKey,
+      badActorATA,
+      userKeypair.publicKey,
+      BigInt(1_000),
+      6,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(mintIx, transferIx),
+        [minterKeypair, userKeypair]
+      ),
+      "Blacklisted"
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("set_mint_introspection", authority.publicKey, stablecoinPDA, Buffer.from([0]))
+      ),
+      [authority]
+    );
+  });
+
+  it("creates a thawed treasury ATA at init when accounts default to frozen", async () => {
+    const treasuryMint = Keypair.generate();
+    const [stablecoinPDA] = findStablecoinPDA(treasuryMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const treasuryATA = await getAssociatedTokenAddress(
+      treasuryMint.publicKey, authority.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+
+    const initIx = buildInitializeIx(
+      authority.publicKey,
+      stablecoinPDA,
+      treasuryMint.publicKey,
+      authorityRole,
+      SSS_HOOK_PROGRAM_ID,
+      {
+        name: "Treasury USD",
+        symbol: "tUSD",
+        uri: "",
+        decimals: 6,
+        enablePermanentDelegate: false,
+        enableTransferHook: false,
+        defaultAccountFrozen: true,
+        createTreasury: true,
+      },
+      [
+        { pubkey: treasuryATA, isSigner: false, isWritable: true },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: stablecoinPDA, isSigner: false, isWritable: false },
+      ]
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(initIx), [authority, treasuryMint]);
+
+    const treasury = await getAccount(connection, treasuryATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(treasury.owner.equals(authority.publicKey)).to.be.true;
+    expect(treasury.isFrozen).to.be.false;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, treasuryMint.publicKey, treasuryATA, BigInt(1_000)
+        )
+      ),
+      [authority]
+    );
+    const balance = await connection.getTokenAccountBalance(treasuryATA);
+    expect(balance.value.amount).to.equal("1000");
+  });
+
+  it("exempts the burn address from destination checks but not the source blacklist", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const other = Keypair.generate().publicKey;
+    const incinerator = Keypair.generate().publicKey;
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [holderEntry] = findBlacklistPDA(stablecoinPDA, holder.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Burnable USD",
+            symbol: "bUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, other);
+    const burnATA = await createTokenAccount(connection, authority, hookMint.publicKey, incinerator);
+
+    const limit = Buffer.alloc(8);
+    limit.writeBigUInt64LE(BigInt(100));
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        ),
+        buildUpdateConfigIx("set_max_transfer_per_tx", authority.publicKey, stablecoinPDA, limit),
+        buildUpdateConfigIx("set_burn_address", authority.publicKey, stablecoinPDA, incinerator.toBuffer())
+      ),
+      [authority]
+    );
+
+    const transferTo = async (destination: typeof burnATA) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            destination,
+            holder.publicKey,
+            BigInt(400),
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    // The transfer limit still applies to ordinary transfers...
+    await expectTxError(transferTo(otherATA), "TransferLimitExceeded");
+    // ...but not to destruction via the burn address
+    await transferTo(burnATA);
+    const balance = await connection.getTokenAccountBalance(burnATA);
+    expect(balance.value.amount).to.equal("400");
+
+    // A blacklisted holder cannot route funds out through the burn address
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildAddToBlacklistIx(
+          authority.publicKey, stablecoinPDA, authorityRole, holderEntry, holder.publicKey, "Sanctions list match"
+        )
+      ),
+      [authority]
+    );
+    await expectTxError(transferTo(burnATA), "Blacklisted");
+  });
+
+  it("logs but allows blacklisted transfers in monitor mode", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const other = Keypair.generate().publicKey;
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [holderEntry] = findBlacklistPDA(stablecoinPDA, holder.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Monitored USD",
+            symbol: "mUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, other);
+    const setHookMode = (mode: number) =>
+      buildUpdateConfigIx("set_hook_mode", authority.publicKey, stablecoinPDA, Buffer.from([mode]));
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        ),
+        buildAddToBlacklistIx(
+          authority.publicKey, stablecoinPDA, authorityRole, holderEntry, holder.publicKey, "Sanctions list match"
+        ),
+        setHookMode(1) // Monitor
+      ),
+      [authority]
+    );
+
+    const transfer = async () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            otherATA,
+            holder.publicKey,
+            BigInt(100),
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    const sig = await transfer();
+    const denied = await fetchEvents(connection, sig, "TransferDenied");
+    expect(denied).to.have.length(1);
+    // mint, source, destination, blacklisted
+    expect(denied[0].subarray(96, 128).equals(holder.publicKey.toBuffer())).to.be.true;
+    const balance = await connection.getTokenAccountBalance(otherATA);
+    expect(balance.value.amount).to.equal("100");
+
+    // Back to enforcement
+    await sendAndConfirmTransaction(connection, new Transaction().add(setHookMode(0)), [authority]);
+    await expectTxError(transfer(), "Blacklisted");
+  });
+
+  it("locks freshly minted tokens for mint_lock_duration", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const other = Keypair.generate().publicKey;
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Locked USD",
+            symbol: "LUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, other);
+    const lockDuration = Buffer.alloc(8);
+    lockDuration.writeBigInt64LE(BigInt(2));
+    const [holderLock] = findMintLockPDA(stablecoinPDA, holderATA);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("set_mint_lock_duration", authority.publicKey, stablecoinPDA, lockDuration),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000),
+          [
+            { pubkey: holderLock, isSigner: false, isWritable: true },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          ]
+        )
+      ),
+      [authority]
+    );
+
+    const transfer = async () =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            otherATA,
+            holder.publicKey,
+            BigInt(100),
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    await expectTxError(transfer(), "MintLocked");
+
+    await new Promise((resolve) => setTimeout(resolve, 3_000));
+    await transfer();
+    const balance = await connection.getTokenAccountBalance(otherATA);
+    expect(balance.value.amount).to.equal("100");
+  });
+
+  it("rejects hook fallback calls that are not a well-formed Execute", async () => {
+    const callHook = (data: Buffer) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(new TransactionInstruction({ keys: [], programId: SSS_HOOK_PROGRAM_ID, data })),
+        [userKeypair]
+      );
+    const executeDisc = crypto
+      .createHash("sha256")
+      .update("spl-transfer-hook-interface:execute")
+      .digest()
+      .subarray(0, 8);
+    const amount = Buffer.alloc(8);
+    amount.writeBigUInt64LE(BigInt(1));
+
+    const wrongDisc = crypto.createHash("sha256").update("spl-transfer-hook-interface:unknown").digest().subarray(0, 8);
+    await expectTxError(callHook(Buffer.concat([wrongDisc, amount])), "InvalidInstruction");
+    await expectTxError(callHook(Buffer.concat([executeDisc, amount.subarray(0, 4)])), "InvalidExecuteData");
+    await expectTxError(callHook(Buffer.concat([executeDisc, amount, Buffer.from([0])])), "InvalidExecuteData");
+  });
+
+  it("lets a seizure from a blacklisted account through the transfer hook", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [holderEntry] = findBlacklistPDA(stablecoinPDA, holder.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Seizable USD",
+            symbol: "ZUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const treasuryATA = await createTokenAccount(connection, authority, hookMint.publicKey, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        ),
+        buildAddToBlacklistIx(
+          authority.publicKey, stablecoinPDA, authorityRole, holderEntry, holder.publicKey, "Sanctions list match"
+        )
+      ),
+      [authority]
+    );
+
+    // The holder can no longer move the funds themselves...
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            treasuryATA,
+            holder.publicKey,
+            BigInt(100),
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      ),
+      "Blacklisted"
+    );
+
+    // ...but the permanent delegate can seize them
+    const seizeIx = buildSeizeIx(
+      authority.publicKey, stablecoinPDA, authorityRole, hookMint.publicKey, holderATA, holder.publicKey, treasuryATA
+    );
+    await addExtraAccountMetasForExecute(
+      connection,
+      seizeIx,
+      SSS_HOOK_PROGRAM_ID,
+      holderATA,
+      hookMint.publicKey,
+      treasuryATA,
+      stablecoinPDA,
+      BigInt(1_000),
+      "confirmed"
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(seizeIx), [authority]);
+
+    const holderBalance = await connection.getTokenAccountBalance(holderATA);
+    const treasuryBalance = await connection.getTokenAccountBalance(treasuryATA);
+    expect(holderBalance.value.amount).to.equal("0");
+    expect(treasuryBalance.value.amount).to.equal("1000");
+  });
+
+  it("rejects transfers to non-rent-exempt destinations when required", async () => {
+    // Deterministic so the preloaded fixture account can belong to this mint;
+    // that account holds 1_000_000 lamports, short of its rent-exempt minimum
+    const hookMint = Keypair.fromSeed(Uint8Array.from({ length: 32 }, (_, i) => i + 1));
+    const rentPayingATA = new PublicKey("vMsVyoJqcxaNDhP2WPp9fbQoP5pwzLXkoLNBuC24qUN");
+    const holder = Keypair.generate();
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Rent USD",
+            symbol: "RNUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const fundedATA = await createTokenAccount(connection, authority, hookMint.publicKey, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx(
+          "set_require_rent_exempt_destination", authority.publicKey, stablecoinPDA, Buffer.from([1])
+        ),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        )
+      ),
+      [authority]
+    );
+
+    const transferTo = async (destination: PublicKey) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            destination,
+            holder.publicKey,
+            BigInt(100),
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    await expectTxError(transferTo(rentPayingATA), "DestinationNotRentExempt");
+    await transferTo(fundedATA);
+    const balance = await connection.getTokenAccountBalance(fundedATA);
+    expect(balance.value.amount).to.equal("100");
+
+    // Seizures are exempt: the permanent delegate may move funds into it
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildAddToBlacklistIx(
+          authority.publicKey, stablecoinPDA, authorityRole, findBlacklistPDA(stablecoinPDA, holder.publicKey)[0],
+          holder.publicKey, "Sanctions list match"
+        )
+      ),
+      [authority]
+    );
+    const seizeIx = buildSeizeIx(
+      authority.publicKey, stablecoinPDA, authorityRole, hookMint.publicKey, holderATA, holder.publicKey, rentPayingATA
+    );
+    await addExtraAccountMetasForExecute(
+      connection,
+      seizeIx,
+      SSS_HOOK_PROGRAM_ID,
+      holderATA,
+      hookMint.publicKey,
+      rentPayingATA,
+      stablecoinPDA,
+      BigInt(900),
+      "confirmed"
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(seizeIx), [authority]);
+    const seized = await connection.getTokenAccountBalance(rentPayingATA);
+    expect(seized.value.amount).to.equal("900");
+  });
+
+  it("rejects holder transfers above max_transfer_per_tx", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const other = Keypair.generate().publicKey;
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Limited USD",
+            symbol: "LUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, other);
+    const limit = Buffer.alloc(8);
+    limit.writeBigUInt64LE(BigInt(250));
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("set_max_transfer_per_tx", authority.publicKey, stablecoinPDA, limit),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        )
+      ),
+      [authority]
+    );
+
+    const transfer = async (amount: bigint) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            otherATA,
+            holder.publicKey,
+            amount,
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    await expectTxError(transfer(BigInt(251)), "TransferLimitExceeded");
+    await transfer(BigInt(250));
+    const balance = await connection.getTokenAccountBalance(otherATA);
+    expect(balance.value.amount).to.equal("250");
+  });
+
+  it("rejects transfers that leave dust below min_hold_balance", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const other = Keypair.generate().publicKey;
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Held USD",
+            symbol: "HUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, other);
+    const minimum = Buffer.alloc(8);
+    minimum.writeBigUInt64LE(BigInt(100));
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("set_min_hold_balance", authority.publicKey, stablecoinPDA, minimum),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        )
+      ),
+      [authority]
+    );
+
+    const transfer = async (amount: bigint) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            otherATA,
+            holder.publicKey,
+            amount,
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    // Would leave 50 behind
+    await expectTxError(transfer(BigInt(950)), "BelowMinHoldBalance");
+
+    // A full exit is always allowed
+    await transfer(BigInt(1_000));
+    const balance = await connection.getTokenAccountBalance(otherATA);
+    expect(balance.value.amount).to.equal("1000");
+  });
+
+  it("stops enforcing a temporary blacklist entry once it expires", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const other = Keypair.generate().publicKey;
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [holderEntry] = findBlacklistPDA(stablecoinPDA, holder.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Temporary USD",
+            symbol: "TMPUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, other);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        )
+      ),
+      [authority]
+    );
+
+    const clock = async () => BigInt((await connection.getBlockTime(await connection.getSlot("confirmed")))!);
+    const blacklist = (expiresAt: bigint, reason: string) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildAddToBlacklistIx(
+            authority.publicKey, stablecoinPDA, authorityRole, holderEntry, holder.publicKey, reason, expiresAt
+          )
+        ),
+        [authority]
+      );
+    const transfer = async (amount: bigint) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            otherATA,
+            holder.publicKey,
+            amount,
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    await expectTxError(blacklist((await clock()) - BigInt(1), "Already over"), "InvalidBlacklistExpiry");
+    const expiresAt = (await clock()) + BigInt(3);
+    await blacklist(expiresAt, "Temporary freeze order");
+    await expectTxError(transfer(BigInt(100)), "Blacklisted");
+
+    while ((await clock()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    // The lapsed entry no longer backs a seizure...
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSeizeIx(
+            authority.publicKey, stablecoinPDA, authorityRole, hookMint.publicKey, holderATA, holder.publicKey, otherATA
+          )
+        ),
+        [authority]
+      ),
+      "BlacklistExpired"
+    );
+    // ...and no longer blocks transfers, without a remove_from_blacklist
+    await transfer(BigInt(101));
+    const balance = await connection.getTokenAccountBalance(otherATA);
+    expect(balance.value.amount).to.equal("101");
+    expect(await connection.getAccountInfo(holderEntry)).to.not.be.null;
+
+    // A re-import overwrites the lapsed entry instead of skipping it
+    const sig = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildImportBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, [holder.publicKey], "Renewed order")
+      ),
+      [authority]
+    );
+    const result = await fetchReturnData(connection, sig);
+    expect(result!.readUInt32LE(0)).to.equal(1);
+    expect((await fetchBlacklistEntry(connection, holderEntry)).reason).to.equal("Renewed order");
+    await expectTxError(transfer(BigInt(1)), "Blacklisted");
+  });
+
+  it("rejects transfers into a denied integration's vault", async () => {
+    const hookMint = Keypair.generate();
+    const holder = Keypair.generate();
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    // Stand-in for an unaudited protocol's vault authority: a PDA with data,
+    // owned by the hook program, which is the entry that gets denied
+    const [vaultAuthority] = findExtraAccountMetaListPDA(hookMint.publicKey);
+
+    const airdrop = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Guarded USD",
+            symbol: "GUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    const holderATA = await createTokenAccount(connection, authority, hookMint.publicKey, holder.publicKey);
+    const vaultATA = await createTokenAccount(connection, authority, hookMint.publicKey, vaultAuthority, true);
+    const otherATA = await createTokenAccount(connection, authority, hookMint.publicKey, Keypair.generate().publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateConfigIx("add_denied_integration", authority.publicKey, stablecoinPDA, SSS_HOOK_PROGRAM_ID.toBuffer()),
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, BigInt(1_000)),
+        buildMintTokensIx(
+          authority.publicKey, stablecoinPDA, authorityRole, minterInfo, hookMint.publicKey, holderATA, BigInt(1_000)
+        )
+      ),
+      [authority]
+    );
+
+    const transferTo = async (destination: PublicKey) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            holderATA,
+            hookMint.publicKey,
+            destination,
+            holder.publicKey,
+            BigInt(100),
+            6,
+            [],
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [holder]
+      );
+
+    await expectTxError(transferTo(vaultATA), "DeniedIntegration");
+    await transferTo(otherATA);
+    expect((await connection.getTokenAccountBalance(otherATA)).value.amount).to.equal("100");
+  });
+
+  it("scopes blacklist removal to the blacklister's namespace", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const sanctions = Keypair.generate();
+    const fraud = Keypair.generate();
+    const flagged = Keypair.generate().publicKey;
+    const [sanctionsRole] = findRolePDA(stablecoinPDA, sanctions.publicKey);
+    const [fraudRole] = findRolePDA(stablecoinPDA, fraud.publicKey);
+    const [entry] = findBlacklistPDA(stablecoinPDA, flagged);
+
+    for (const kp of [sanctions, fraud]) {
+      const sig = await connection.requestAirdrop(kp.publicKey, LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+    }
+
+    const blacklisterIn = (blacklistNamespace: number) => ({
+      isMinter: false, isBurner: false, isPauser: false, isBlacklister: true, isSeizer: false, blacklistNamespace,
+    });
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, sanctionsRole, sanctions.publicKey, blacklisterIn(1)),
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, fraudRole, fraud.publicKey, blacklisterIn(2))
+      ),
+      [authority]
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildAddToBlacklistIx(sanctions.publicKey, stablecoinPDA, sanctionsRole, entry, flagged, "OFAC SDN match")
+      ),
+      [sanctions]
+    );
+
+    const remove = (signer: Keypair, role: typeof entry) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildRemoveFromBlacklistIx(signer.publicKey, stablecoinPDA, role, entry, flagged)),
+        [signer]
+      );
+
+    await expectTxError(remove(fraud, fraudRole), "BlacklistNamespaceMismatch");
+    await remove(sanctions, sanctionsRole);
+    expect(await connection.getAccountInfo(entry)).to.be.null;
+  });
+
+  it("refuses to initialize an ExtraAccountMetaList twice", async () => {
+    const hookMint = Keypair.generate();
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Meta USD",
+            symbol: "mtUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    // The compute budget instruction only keeps the retry from being a duplicate transaction
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+          buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+        ),
+        [authority]
+      ),
+      "AlreadyInitialized"
+    );
+  });
+
+  it("refreshes an ExtraAccountMetaList with the current extra accounts", async () => {
+    const hookMint = Keypair.generate();
+    const outsider = Keypair.generate();
+    const [stablecoinPDA] = findStablecoinPDA(hookMint.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [metaList] = findExtraAccountMetaListPDA(hookMint.publicKey);
+
+    const airdrop = await connection.requestAirdrop(outsider.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(airdrop);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          hookMint.publicKey,
+          authorityRole,
+          SSS_HOOK_PROGRAM_ID,
+          {
+            name: "Refresh USD",
+            symbol: "rfUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: true,
+            enableTransferHook: true,
+            defaultAccountFrozen: false,
+          }
+        ),
+        buildInitializeExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey)
+      ),
+      [authority, hookMint]
+    );
+
+    await expectTxError(
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateExtraAccountMetaListIx(outsider.publicKey, hookMint.publicKey, stablecoinPDA)),
+        [outsider]
+      ),
+      "Unauthorized"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildUpdateExtraAccountMetaListIx(authority.publicKey, hookMint.publicKey, stablecoinPDA)),
+      [authority]
+    );
+
+    // TLV: 8-byte Execute discriminator, 4-byte length, 4-byte count, then
+    // 35-byte metas (discriminator, address config, is_signer, is_writable)
+    const data = (await connection.getAccountInfo(metaList))!.data;
+    const meta = (i: number) => data.subarray(16 + 35 * i, 16 + 35 * (i + 1));
+    expect(data.readUInt32LE(12)).to.equal(5);
+    expect(meta(0).subarray(1, 33).equals(SSS_TOKEN_PROGRAM_ID.toBuffer())).to.be.true;
+    // [9] mint lock: external PDA of the program at index 5, first seed "mint_lock"
+    expect(meta(4)[0]).to.equal(128 + 5);
+    expect(meta(4).subarray(3, 12).toString()).to.equal("mint_lock");
+  });
 });